@@ -0,0 +1,82 @@
+use alloc::vec::Vec;
+use core::{cell::RefCell, marker::PhantomData};
+
+use crate::ScopedLocalBoxFuture;
+
+const INITIAL_CAPACITY: usize = 16;
+
+/// An arena which hands out references to the values allocated into it for as long as the scope of
+/// [`scope_arena`] lasts, so that results can be built without a separate heap allocation per item.
+///
+/// Values are stored in chunks which are never reallocated, and are all dropped together with the arena.
+/// This is local to the task which runs the scope and is not [`Sync`].
+pub struct ScopedArena<'subject, T> {
+    chunks: RefCell<Vec<Vec<T>>>,
+    scope: PhantomData<&'subject ()>,
+}
+
+impl<T> ScopedArena<'_, T> {
+    fn new() -> Self {
+        Self { chunks: RefCell::new(Vec::new()), scope: PhantomData }
+    }
+
+    /// Moves `value` into the arena, returning a reference to it which lives as long as the arena.
+    pub fn alloc(&self, value: T) -> &T {
+        let mut chunks = self.chunks.borrow_mut();
+        if chunks.last().is_none_or(|chunk| chunk.len() == chunk.capacity()) {
+            let capacity = chunks.last().map_or(INITIAL_CAPACITY, |chunk| chunk.capacity() * 2);
+            chunks.push(Vec::with_capacity(capacity));
+        }
+        let chunk = chunks.last_mut().expect("a chunk with spare capacity was just ensured");
+        chunk.push(value);
+        let value: *const T = chunk.last().expect("a value was just pushed");
+        // SAFETY: chunks are only pushed to while below their capacity and are never shrunk or dropped before the
+        // arena, so the value keeps its address and is never mutated for the lifetime of `self`.
+        unsafe { &*value }
+    }
+
+    /// Returns the number of values allocated into the arena.
+    pub fn len(&self) -> usize {
+        self.chunks.borrow().iter().map(Vec::len).sum()
+    }
+
+    /// Returns whether no values have been allocated into the arena.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Runs the future returned by `f` with a [`ScopedArena`] which it can allocate into and borrow from.
+/// References into the arena cannot outlive the future, so its output has to be built from them before it resolves.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use scoped_futures::{scope_arena, ScopedFutureExt};
+///
+/// pub struct Row<'a> {
+///     name: &'a str,
+///     score: u32,
+/// }
+///
+/// let names = vec!["ann", "bob", "cat"];
+/// let best = futures::executor::block_on(scope_arena(|arena| {
+///     async move {
+///         let rows: Vec<&Row> = names.iter().zip(1..).map(|(name, score)| arena.alloc(Row { name, score })).collect();
+///         assert_eq!(3, arena.len());
+///         let best = rows.iter().max_by_key(|row| row.score).unwrap();
+///         format!("{} scored {}", best.name, best.score)
+///     }
+///     .scope_boxed_local()
+/// }));
+/// assert_eq!("cat scored 3", best);
+/// # } #[cfg(feature = "alloc")] test();
+/// ```
+pub async fn scope_arena<'subject, T, U, F>(f: F) -> U
+where
+    T: 'subject,
+    F: for<'arena> FnOnce(&'arena ScopedArena<'subject, T>) -> ScopedLocalBoxFuture<'subject, 'arena, U>,
+{
+    let arena = ScopedArena::new();
+    f(&arena).await
+}
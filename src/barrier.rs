@@ -0,0 +1,139 @@
+use crate::spin_lock::SpinLock;
+use alloc::vec::Vec;
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+struct State {
+    arrived: usize,
+    generation: usize,
+    wakers: Vec<Waker>,
+}
+
+/// A barrier which releases its waiters once `parties` futures within a scope have arrived at it.
+/// The barrier can be reused once released, and may be shared by futures running on different threads.
+pub struct ScopedBarrier {
+    parties: usize,
+    state: SpinLock<State>,
+}
+
+impl ScopedBarrier {
+    /// Creates a barrier which releases waiters once `parties` of them have arrived.
+    pub fn new(parties: usize) -> Self {
+        Self { parties, state: SpinLock::new(State { arrived: 0, generation: 0, wakers: Vec::new() }) }
+    }
+
+    /// Arrives at this barrier, resolving once every party has arrived.
+    pub fn wait(&self) -> BarrierWait<'_> {
+        BarrierWait { barrier: self, generation: None }
+    }
+}
+
+/// Future for the [`ScopedBarrier::wait`] method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct BarrierWait<'barrier> {
+    barrier: &'barrier ScopedBarrier,
+    generation: Option<usize>,
+}
+
+impl Future for BarrierWait<'_> {
+    type Output = ();
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let (parties, generation) = (self.barrier.parties, self.generation);
+        let released = self.barrier.state.with(|state| {
+            match generation {
+                Some(generation) if generation != state.generation => return Ok(Vec::new()),
+                Some(_) => {}
+                None => {
+                    state.arrived += 1;
+                    if state.arrived >= parties {
+                        state.arrived = 0;
+                        state.generation += 1;
+                        return Ok(core::mem::take(&mut state.wakers));
+                    }
+                }
+            }
+            if !state.wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+                state.wakers.push(cx.waker().clone());
+            }
+            Err(state.generation)
+        });
+        match released {
+            Ok(wakers) => {
+                wakers.into_iter().for_each(Waker::wake);
+                Poll::Ready(())
+            }
+            Err(generation) => {
+                self.generation = Some(generation);
+                Poll::Pending
+            }
+        }
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_at_barrier`](crate::ScopedFutureExt::scope_at_barrier) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use core::{cell::RefCell, sync::atomic::{AtomicUsize, Ordering}};
+    /// use scoped_futures::{ScopedBarrier, ScopedBoxFuture, ScopedFutureExt};
+    /// # use scoped_futures::yield_now;
+    ///
+    /// let log = RefCell::new(vec![]);
+    /// let barrier = ScopedBarrier::new(2);
+    /// futures::executor::block_on(futures::future::join(
+    ///     async {
+    ///         log.borrow_mut().push("a arrived");
+    ///         async { log.borrow_mut().push("a started") }.scope_at_barrier(&barrier).await
+    ///     },
+    ///     async {
+    ///         yield_now().await;
+    ///         log.borrow_mut().push("b arrived");
+    ///         async { log.borrow_mut().push("b started") }.scope_at_barrier(&barrier).await
+    ///     },
+    /// ));
+    /// assert_eq!(vec!["a arrived", "b arrived", "b started", "a started"], log.into_inner());
+    ///
+    /// // the barrier also synchronises futures running on different threads
+    /// let barrier = ScopedBarrier::new(2);
+    /// let arrived = AtomicUsize::new(0);
+    /// std::thread::scope(|scope| {
+    ///     let threads: Vec<_> = (0..2).map(|_| scope.spawn(|| {
+    ///         arrived.fetch_add(1, Ordering::SeqCst);
+    ///         let future: ScopedBoxFuture<'_, '_, usize> = async { arrived.load(Ordering::SeqCst) }.scope_at_barrier(&barrier).scope_boxed();
+    ///         futures::executor::block_on(future)
+    ///     })).collect();
+    ///     threads.into_iter().for_each(|thread| assert_eq!(2, thread.join().unwrap()));
+    /// });
+    /// # } #[cfg(feature = "alloc")] test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct AtBarrier<'barrier, Fut> {
+        #[pin]
+        wait: Option<BarrierWait<'barrier>>,
+        #[pin]
+        future: Fut,
+    }
+}
+
+impl<'barrier, Fut> AtBarrier<'barrier, Fut> {
+    pub(crate) fn new(future: Fut, barrier: &'barrier ScopedBarrier) -> Self {
+        Self { wait: Some(barrier.wait()), future }
+    }
+}
+
+impl<Fut: Future> Future for AtBarrier<'_, Fut> {
+    type Output = Fut::Output;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        if let Some(wait) = this.wait.as_mut().as_pin_mut() {
+            core::task::ready!(wait.poll(cx));
+            this.wait.set(None);
+        }
+        this.future.poll(cx)
+    }
+}
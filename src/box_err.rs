@@ -0,0 +1,69 @@
+use alloc::boxed::Box;
+use core::{
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_core::TryFuture;
+
+/// A boxed error which may borrow data for `'subject`.
+pub type ScopedBoxError<'subject> = Box<dyn core::error::Error + Send + 'subject>;
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_box_err`](crate::TryScopedFutureExt::scope_box_err) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use core::fmt;
+    /// use scoped_futures::{ScopedBoxError, TryScopedFutureExt};
+    ///
+    /// #[derive(Debug)]
+    /// pub struct EmptyRow<'a> {
+    ///     table: &'a str,
+    /// }
+    ///
+    /// impl fmt::Display for EmptyRow<'_> {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "empty row in {}", self.table)
+    ///     }
+    /// }
+    ///
+    /// impl core::error::Error for EmptyRow<'_> {}
+    ///
+    /// async fn parse<'a>(table: &'a str, row: &'a str) -> Result<u8, ScopedBoxError<'a>> {
+    ///     if row.is_empty() {
+    ///         return async { Err(EmptyRow { table }) }.scope_box_err().await;
+    ///     }
+    ///     async { row.parse::<u8>() }.scope_box_err().await
+    /// }
+    ///
+    /// let table = String::from("users");
+    /// futures::executor::block_on(async {
+    ///     assert_eq!(1, parse(&table, "1").await.unwrap());
+    ///     assert_eq!("empty row in users", parse(&table, "").await.unwrap_err().to_string());
+    ///     assert_eq!("invalid digit found in string", parse(&table, "x").await.unwrap_err().to_string());
+    /// });
+    /// # } #[cfg(feature = "alloc")] test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct BoxErr<'subject, Fut> {
+        #[pin]
+        future: Fut,
+        scope: PhantomData<&'subject ()>,
+    }
+}
+
+impl<Fut> BoxErr<'_, Fut> {
+    pub(crate) fn new(future: Fut) -> Self {
+        Self { future, scope: PhantomData }
+    }
+}
+
+impl<'subject, Fut: TryFuture<Error: core::error::Error + Send + 'subject>> Future for BoxErr<'subject, Fut> {
+    type Output = Result<Fut::Ok, ScopedBoxError<'subject>>;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().future.try_poll(cx).map_err(|error| Box::new(error) as ScopedBoxError<'subject>)
+    }
+}
@@ -0,0 +1,53 @@
+use crate::ScopedBoxFuture;
+use alloc::boxed::Box;
+
+/// A callback which re-borrows `Arg` and returns a [`ScopedBoxFuture`] bounded by `'upper_bound`.
+///
+/// This is implemented for every matching [`FnOnce`], so it can stand in for spelling out
+/// `for<'subject> FnOnce(&'subject mut Arg) -> ScopedBoxFuture<'upper_bound, 'subject, T>` in full.
+pub trait ScopedCallback<'upper_bound, Arg: ?Sized, T>: for<'subject> FnOnce(&'subject mut Arg) -> ScopedBoxFuture<'upper_bound, 'subject, T> {}
+
+impl<'upper_bound, Arg: ?Sized, T, F> ScopedCallback<'upper_bound, Arg, T> for F where
+    F: for<'subject> FnOnce(&'subject mut Arg) -> ScopedBoxFuture<'upper_bound, 'subject, T>
+{
+}
+
+/// A boxed [`ScopedCallback`], which can be returned from the scoped future of an outer callback so that
+/// the borrowing work it performs is deferred while its lifetime stays bounded by the outer scope.
+pub type ScopedBoxCallback<'upper_bound, Arg, T> = Box<dyn ScopedCallback<'upper_bound, Arg, T> + Send + 'upper_bound>;
+
+/// Boxes `callback` into a [`ScopedBoxCallback`].
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use scoped_futures::{scoped_callback, ScopedBoxCallback, ScopedBoxFuture, ScopedFutureExt};
+///
+/// fn prepare<'a>(prefix: &'a str) -> ScopedBoxFuture<'a, 'a, ScopedBoxCallback<'a, Vec<String>, usize>> {
+///     async move {
+///         scoped_callback(move |rows: &mut Vec<String>| {
+///             async move {
+///                 rows.push(format!("{prefix}-{}", rows.len()));
+///                 rows.len()
+///             }
+///             .scope_boxed()
+///         })
+///     }
+///     .scope_boxed()
+/// }
+///
+/// futures::executor::block_on(async {
+///     let prefix = String::from("row");
+///     let mut rows = vec![];
+///     let callback = prepare(&prefix).await;
+///     assert_eq!(1, callback(&mut rows).await);
+///     assert_eq!(vec!["row-0"], rows);
+/// });
+/// # } #[cfg(feature = "alloc")] test();
+/// ```
+pub fn scoped_callback<'upper_bound, Arg: ?Sized, T, F>(callback: F) -> ScopedBoxCallback<'upper_bound, Arg, T>
+where
+    F: for<'subject> FnOnce(&'subject mut Arg) -> ScopedBoxFuture<'upper_bound, 'subject, T> + Send + 'upper_bound,
+{
+    Box::new(callback)
+}
@@ -0,0 +1,98 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_with_cancel_reason`](crate::ScopedFutureExt::scope_with_cancel_reason) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use core::cell::RefCell;
+    /// use core::{future::Future, task::{Context, Poll}};
+    /// use scoped_futures::ScopedFutureExt;
+    ///
+    /// #[derive(Debug, Default, PartialEq)]
+    /// pub enum Reason {
+    ///     Timeout,
+    ///     Abort,
+    ///     #[default]
+    ///     ParentDropped,
+    /// }
+    ///
+    /// let rollbacks = RefCell::new(vec![]);
+    /// let transaction = || futures::future::pending::<()>().scope_with_cancel_reason(|reason| rollbacks.borrow_mut().push(reason));
+    /// let waker = futures::task::noop_waker();
+    /// let mut cx = Context::from_waker(&waker);
+    ///
+    /// // a timeout elapsed while the transaction was pending
+    /// let mut future = Box::pin(transaction());
+    /// let timed_out = futures::executor::block_on(futures::future::select(future.as_mut(), futures::future::ready(())));
+    /// assert!(matches!(timed_out, futures::future::Either::Right(_)));
+    /// future.as_mut().set_cancel_reason(Reason::Timeout);
+    /// drop(future);
+    ///
+    /// // the transaction was explicitly aborted
+    /// let mut future = Box::pin(transaction());
+    /// assert_eq!(Poll::Pending, future.as_mut().poll(&mut cx));
+    /// future.as_mut().set_cancel_reason(Reason::Abort);
+    /// drop(future);
+    ///
+    /// // the enclosing scope dropped the transaction
+    /// drop(transaction());
+    ///
+    /// // completed futures are not cancelled
+    /// futures::executor::block_on(async {}.scope_with_cancel_reason(|reason: Reason| rollbacks.borrow_mut().push(reason)));
+    ///
+    /// assert_eq!(vec![Reason::Timeout, Reason::Abort, Reason::ParentDropped], rollbacks.into_inner());
+    /// # } test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct WithCancelReason<Fut, F, R>
+    where
+        F: FnOnce(R),
+        R: Default,
+    {
+        #[pin]
+        future: Fut,
+        on_cancel: Option<F>,
+        reason: Option<R>,
+    }
+
+    impl<Fut, F, R> PinnedDrop for WithCancelReason<Fut, F, R>
+    where
+        F: FnOnce(R),
+        R: Default,
+    {
+        fn drop(this: Pin<&mut Self>) {
+            let this = this.project();
+            if let Some(on_cancel) = this.on_cancel.take() {
+                on_cancel(this.reason.take().unwrap_or_default());
+            }
+        }
+    }
+}
+
+impl<Fut, F: FnOnce(R), R: Default> WithCancelReason<Fut, F, R> {
+    pub(crate) fn new(future: Fut, on_cancel: F) -> Self {
+        Self { future, on_cancel: Some(on_cancel), reason: None }
+    }
+
+    /// Sets the reason passed to the cancellation callback if this future is dropped before completing.
+    /// Without a reason, [`Default::default`] is used.
+    pub fn set_cancel_reason(self: Pin<&mut Self>, reason: R) {
+        *self.project().reason = Some(reason);
+    }
+}
+
+impl<Fut: Future, F: FnOnce(R), R: Default> Future for WithCancelReason<Fut, F, R> {
+    type Output = Fut::Output;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let output = core::task::ready!(this.future.poll(cx));
+        this.on_cancel.take();
+        Poll::Ready(output)
+    }
+}
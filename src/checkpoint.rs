@@ -0,0 +1,85 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A [`Future`] which signals when it has reached a point at which intermediate work can be committed.
+pub trait Checkpoint: Future {
+    /// Describes the checkpoint which was reached.
+    type Marker;
+
+    /// Takes the checkpoint reached since the last call, if any.
+    fn take_checkpoint(self: Pin<&mut Self>) -> Option<Self::Marker>;
+}
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_checkpoint`](crate::ScopedFutureExt::scope_checkpoint) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use core::{future::Future, pin::Pin, task::{Context, Poll}};
+    /// use scoped_futures::{Checkpoint, ScopedFutureExt};
+    ///
+    /// // completes one stage per poll, reaching a checkpoint after each stage
+    /// pub struct Stages {
+    ///     stage: u8,
+    ///     reached: Option<u8>,
+    /// }
+    ///
+    /// impl Future for Stages {
+    ///     type Output = u8;
+    ///     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u8> {
+    ///         if self.stage == 3 {
+    ///             return Poll::Ready(self.stage);
+    ///         }
+    ///         self.stage += 1;
+    ///         self.reached = Some(self.stage);
+    ///         cx.waker().wake_by_ref();
+    ///         Poll::Pending
+    ///     }
+    /// }
+    ///
+    /// impl Checkpoint for Stages {
+    ///     type Marker = u8;
+    ///     fn take_checkpoint(mut self: Pin<&mut Self>) -> Option<u8> {
+    ///         self.reached.take()
+    ///     }
+    /// }
+    ///
+    /// let mut commits = vec![];
+    /// let stages = futures::executor::block_on(
+    ///     Stages { stage: 0, reached: None }.scope_checkpoint(|stage| commits.push(stage)),
+    /// );
+    /// assert_eq!(3, stages);
+    /// assert_eq!(vec![1, 2, 3], commits);
+    /// # } test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct WithCheckpoints<Fut, F> {
+        #[pin]
+        future: Fut,
+        on_checkpoint: F,
+    }
+}
+
+impl<Fut, F> WithCheckpoints<Fut, F> {
+    pub(crate) fn new(future: Fut, on_checkpoint: F) -> Self {
+        Self { future, on_checkpoint }
+    }
+}
+
+impl<Fut: Checkpoint, F: FnMut(Fut::Marker)> Future for WithCheckpoints<Fut, F> {
+    type Output = Fut::Output;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        let poll = this.future.as_mut().poll(cx);
+        if poll.is_pending() {
+            if let Some(marker) = this.future.take_checkpoint() {
+                (this.on_checkpoint)(marker);
+            }
+        }
+        poll
+    }
+}
@@ -0,0 +1,100 @@
+use alloc::{sync::Arc, task::Wake};
+use core::{
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicBool, Ordering},
+    task::{Context, Poll, Waker},
+};
+
+struct CoalescingWaker {
+    woken: AtomicBool,
+    waker: Waker,
+}
+
+impl Wake for CoalescingWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref()
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        if !self.woken.swap(true, Ordering::AcqRel) {
+            self.waker.wake_by_ref();
+        }
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_coalesce_wakes`](crate::ScopedFutureExt::scope_coalesce_wakes) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use core::{future::Future, pin::pin, task::{Context, Poll, Waker}};
+    /// use scoped_futures::ScopedFutureExt;
+    /// use std::{sync::{atomic::{AtomicUsize, Ordering}, Arc}, task::Wake};
+    ///
+    /// #[derive(Default)]
+    /// pub struct CountWakes(AtomicUsize);
+    ///
+    /// impl Wake for CountWakes {
+    ///     fn wake(self: Arc<Self>) {
+    ///         self.0.fetch_add(1, Ordering::SeqCst);
+    ///     }
+    /// }
+    ///
+    /// let wakes = Arc::new(CountWakes::default());
+    /// let waker = Waker::from(wakes.clone());
+    /// let mut cx = Context::from_waker(&waker);
+    ///
+    /// let mut polls = 0;
+    /// let mut future = pin!(futures::future::poll_fn(|cx| {
+    ///     polls += 1;
+    ///     if polls == 1 {
+    ///         cx.waker().wake_by_ref();
+    ///         cx.waker().wake_by_ref();
+    ///     }
+    ///     Poll::<()>::Pending
+    /// }).scope_coalesce_wakes());
+    ///
+    /// assert!(future.as_mut().poll(&mut cx).is_pending());
+    /// assert_eq!(1, wakes.0.load(Ordering::SeqCst));
+    /// // the first poll after a wake reaches the inner future, spurious polls after it do not
+    /// assert!(future.as_mut().poll(&mut cx).is_pending());
+    /// assert!(future.as_mut().poll(&mut cx).is_pending());
+    /// drop(future);
+    /// assert_eq!(2, polls);
+    /// # } #[cfg(feature = "std")] test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct CoalesceWakes<Fut> {
+        #[pin]
+        future: Fut,
+        waker: Option<(Arc<CoalescingWaker>, Waker)>,
+    }
+}
+
+impl<Fut> CoalesceWakes<Fut> {
+    pub(crate) fn new(future: Fut) -> Self {
+        Self { future, waker: None }
+    }
+}
+
+impl<Fut: Future> Future for CoalesceWakes<Fut> {
+    type Output = Fut::Output;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let waker = match this.waker {
+            Some((state, waker)) if state.waker.will_wake(cx.waker()) => {
+                if !state.woken.swap(false, Ordering::AcqRel) {
+                    return Poll::Pending;
+                }
+                &*waker
+            }
+            _ => {
+                let state = Arc::new(CoalescingWaker { woken: AtomicBool::new(false), waker: cx.waker().clone() });
+                &this.waker.insert((state.clone(), Waker::from(state))).1
+            }
+        };
+        this.future.poll(&mut Context::from_waker(waker))
+    }
+}
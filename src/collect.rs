@@ -0,0 +1,97 @@
+use core::{
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_collect`](crate::ScopedFutureExt::scope_collect) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use scoped_futures::ScopedFutureExt;
+    ///
+    /// async fn active<'a>(users: &'a [(&'a str, bool)]) -> impl Iterator<Item = &'a str> + 'a {
+    ///     users.iter().filter(|(_, active)| *active).map(|(name, _)| *name)
+    /// }
+    ///
+    /// let users = vec![("ann", true), ("bob", false), ("cat", true)];
+    /// let names: Vec<&str> = futures::executor::block_on(active(&users).scope_collect());
+    /// assert_eq!(vec!["ann", "cat"], names);
+    /// # } test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Collect<Fut, C> {
+        #[pin]
+        future: Fut,
+        collection: PhantomData<fn() -> C>,
+    }
+}
+
+impl<Fut, C> Collect<Fut, C> {
+    pub(crate) fn new(future: Fut) -> Self {
+        Self { future, collection: PhantomData }
+    }
+}
+
+impl<Fut: Future<Output: IntoIterator>, C: FromIterator<<Fut::Output as IntoIterator>::Item>> Future for Collect<Fut, C> {
+    type Output = C;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().future.poll(cx).map(C::from_iter)
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_collect_map`](crate::ScopedFutureExt::scope_collect_map) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use scoped_futures::ScopedFutureExt;
+    ///
+    /// pub struct Db {
+    ///     names: Vec<String>,
+    /// }
+    ///
+    /// async fn names(db: &Db) -> impl Iterator<Item = &String> {
+    ///     db.names.iter()
+    /// }
+    ///
+    /// let names: Vec<String> = futures::executor::block_on(async {
+    ///     let db = Db { names: vec![String::from("ann"), String::from("bob")] };
+    ///     names(&db).scope_collect_map(|name| name.to_uppercase()).await
+    /// });
+    /// assert_eq!(vec![String::from("ANN"), String::from("BOB")], names);
+    /// # } test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct CollectMap<Fut, F, C> {
+        #[pin]
+        future: Fut,
+        f: Option<F>,
+        collection: PhantomData<fn() -> C>,
+    }
+}
+
+impl<Fut, F, C> CollectMap<Fut, F, C> {
+    pub(crate) fn new(future: Fut, f: F) -> Self {
+        Self { future, f: Some(f), collection: PhantomData }
+    }
+}
+
+impl<Fut, F, U, C> Future for CollectMap<Fut, F, C>
+where
+    Fut: Future<Output: IntoIterator>,
+    F: FnMut(<Fut::Output as IntoIterator>::Item) -> U,
+    C: FromIterator<U>,
+{
+    type Output = C;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let output = core::task::ready!(this.future.poll(cx));
+        let f = this.f.take().expect("CollectMap polled after completion");
+        Poll::Ready(output.into_iter().map(f).collect())
+    }
+}
@@ -0,0 +1,113 @@
+use crate::spin_lock::SpinLock;
+use alloc::sync::Arc;
+use core::{
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicBool, Ordering},
+    task::{Context, Poll, Waker},
+};
+
+#[derive(Default)]
+struct State {
+    done: AtomicBool,
+    waker: SpinLock<Option<Waker>>,
+}
+
+impl State {
+    fn signal(&self) {
+        self.done.store(true, Ordering::Release);
+        if let Some(waker) = self.waker.with(Option::take) {
+            waker.wake();
+        }
+    }
+}
+
+struct Handle(Arc<State>);
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        self.0.signal();
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_completion_signal`](crate::ScopedFutureExt::scope_completion_signal) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use core::cell::RefCell;
+    /// use scoped_futures::{ScopedBoxFuture, ScopedFutureExt};
+    /// # use scoped_futures::yield_now;
+    ///
+    /// let log = RefCell::new(vec![]);
+    /// let (insert, inserted) = async {
+    ///     yield_now().await;
+    ///     log.borrow_mut().push("insert");
+    ///     1
+    /// }
+    /// .scope_completion_signal();
+    /// let notify = async {
+    ///     inserted.await;
+    ///     log.borrow_mut().push("notify");
+    /// };
+    /// let (count, ()) = futures::executor::block_on(futures::future::join(insert, notify));
+    /// assert_eq!(1, count);
+    /// assert_eq!(vec!["insert", "notify"], log.into_inner());
+    ///
+    /// // the signal may be awaited on another thread
+    /// let (insert, inserted) = async { 2 }.scope_completion_signal();
+    /// let inserted: ScopedBoxFuture<'_, '_, ()> = inserted.scope_boxed();
+    /// std::thread::scope(|scope| {
+    ///     let notify = scope.spawn(|| futures::executor::block_on(inserted));
+    ///     assert_eq!(2, futures::executor::block_on(insert));
+    ///     notify.join().unwrap();
+    /// });
+    /// # } #[cfg(feature = "alloc")] test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Signaled<Fut> {
+        #[pin]
+        future: Fut,
+        handle: Option<Handle>,
+    }
+}
+
+/// Future for the [`scope_completion_signal`](crate::ScopedFutureExt::scope_completion_signal) method,
+/// resolving once its [`Signaled`] future completes or is dropped.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct CompletionSignal {
+    state: Arc<State>,
+}
+
+impl<Fut> Signaled<Fut> {
+    pub(crate) fn new(future: Fut) -> (Self, CompletionSignal) {
+        let state = Arc::new(State::default());
+        (Self { future, handle: Some(Handle(state.clone())) }, CompletionSignal { state })
+    }
+}
+
+impl<Fut: Future> Future for Signaled<Fut> {
+    type Output = Fut::Output;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let output = core::task::ready!(this.future.poll(cx));
+        *this.handle = None;
+        Poll::Ready(output)
+    }
+}
+
+impl Future for CompletionSignal {
+    type Output = ();
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.state.done.load(Ordering::Acquire) {
+            return Poll::Ready(());
+        }
+        self.state.waker.with(|waker| *waker = Some(cx.waker().clone()));
+        // the signal may have been sent before the waker was stored
+        match self.state.done.load(Ordering::Acquire) {
+            true => Poll::Ready(()),
+            false => Poll::Pending,
+        }
+    }
+}
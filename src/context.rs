@@ -0,0 +1,76 @@
+use core::{
+    any::TypeId,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use std::{cell::RefCell, thread_local, vec::Vec};
+
+thread_local! {
+    static CONTEXTS: RefCell<Vec<(TypeId, *const ())>> = const { RefCell::new(Vec::new()) };
+}
+
+struct Pop;
+
+impl Drop for Pop {
+    fn drop(&mut self) {
+        CONTEXTS.with_borrow_mut(|contexts| contexts.pop());
+    }
+}
+
+/// Calls `f` with the innermost context of type `C` injected by
+/// [`scope_with_context`](crate::ScopedFutureExt::scope_with_context) into the future currently being polled
+/// on this thread, or with [`None`] if there is no such context.
+pub fn with_context<C: 'static, R>(f: impl FnOnce(Option<&C>) -> R) -> R {
+    let context = CONTEXTS.with_borrow(|contexts| contexts.iter().rev().find(|(type_id, _)| *type_id == TypeId::of::<C>()).map(|(_, context)| *context));
+    // SAFETY: contexts are only on the stack for the duration of a poll of the future borrowing them,
+    // and `f` cannot hold on to the reference beyond this call
+    f(context.map(|context| unsafe { &*(context as *const C) }))
+}
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_with_context`](crate::ScopedFutureExt::scope_with_context) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use scoped_futures::{with_context, ScopedFutureExt};
+    ///
+    /// pub struct RequestId(u64);
+    ///
+    /// async fn insert(row: &str) -> String {
+    ///     let id = with_context(|id: Option<&RequestId>| id.map_or(0, |id| id.0));
+    ///     format!("{id}: {row}")
+    /// }
+    ///
+    /// futures::executor::block_on(async {
+    ///     let id = RequestId(7);
+    ///     assert_eq!("7: row", insert("row").scope_with_context(&id).await);
+    ///     assert_eq!("0: row", insert("row").await);
+    /// });
+    /// # } #[cfg(feature = "std")] test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct WithContext<'subject, Fut, C> {
+        #[pin]
+        future: Fut,
+        context: &'subject C,
+    }
+}
+
+impl<'subject, Fut, C> WithContext<'subject, Fut, C> {
+    pub(crate) fn new(future: Fut, context: &'subject C) -> Self {
+        Self { future, context }
+    }
+}
+
+impl<Fut: Future, C: 'static> Future for WithContext<'_, Fut, C> {
+    type Output = Fut::Output;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let context = *this.context as *const C as *const ();
+        CONTEXTS.with_borrow_mut(|contexts| contexts.push((TypeId::of::<C>(), context)));
+        let _pop = Pop;
+        this.future.poll(cx)
+    }
+}
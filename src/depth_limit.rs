@@ -0,0 +1,116 @@
+use core::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Error returned by [`scope_depth_limited`] when nesting more scoped futures would exceed the depth limit.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DepthExceeded {
+    /// The maximum nesting depth which was exceeded.
+    pub max_depth: usize,
+}
+
+impl fmt::Display for DepthExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "scoped future nesting exceeded a depth of {}", self.max_depth)
+    }
+}
+
+impl core::error::Error for DepthExceeded {}
+
+/// Nesting depth handed to each future built by [`scope_depth_limited`], used to build further nested futures.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DepthLimit {
+    depth: usize,
+    max_depth: usize,
+}
+
+impl DepthLimit {
+    /// The depth of the current scope, starting at `1` for the outermost one.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// The maximum depth that nested scopes may reach.
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Builds a future one level deeper than this one, failing with [`DepthExceeded`] instead of calling `make_fut` if that passes the limit.
+    pub fn nest<Fut, F>(self, make_fut: F) -> DepthLimited<Fut>
+    where
+        Fut: Future,
+        F: FnOnce(DepthLimit) -> Fut,
+    {
+        DepthLimited::new(self.depth + 1, self.max_depth, make_fut)
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_depth_limited`] function and the [`DepthLimit::nest`] method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct DepthLimited<Fut> {
+        #[pin]
+        future: Option<Fut>,
+        exceeded: Option<DepthExceeded>,
+    }
+}
+
+impl<Fut: Future> DepthLimited<Fut> {
+    fn new<F: FnOnce(DepthLimit) -> Fut>(depth: usize, max_depth: usize, make_fut: F) -> Self {
+        if depth > max_depth {
+            return Self { future: None, exceeded: Some(DepthExceeded { max_depth }) };
+        }
+        Self { future: Some(make_fut(DepthLimit { depth, max_depth })), exceeded: None }
+    }
+}
+
+/// Builds a scoped future from `make_fut`, handing it a [`DepthLimit`] through which recursive calls nest further scoped futures.
+/// Once nesting passes `max_depth` the innermost future resolves to [`DepthExceeded`] without being built, guarding recursive logic from exhausting the stack or heap.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use scoped_futures::{scope_depth_limited, DepthExceeded, DepthLimit, ScopedBoxFuture, ScopedFutureExt};
+///
+/// fn walk<'a>(tree: &'a [u8], limit: DepthLimit) -> ScopedBoxFuture<'a, 'a, Result<usize, DepthExceeded>> {
+///     async move {
+///         match tree.split_first() {
+///             None => Ok(limit.depth()),
+///             Some((_, rest)) => limit.nest(|limit| walk(rest, limit)).await?,
+///         }
+///     }
+///     .scope_boxed()
+/// }
+///
+/// let tree = vec![1, 2, 3];
+/// futures::executor::block_on(async {
+///     assert_eq!(Ok(Ok(4)), scope_depth_limited(4, |limit| walk(&tree, limit)).await);
+///     assert_eq!(Ok(Err(DepthExceeded { max_depth: 3 })), scope_depth_limited(3, |limit| walk(&tree, limit)).await);
+///     assert_eq!(Err(DepthExceeded { max_depth: 0 }), scope_depth_limited(0, |limit| walk(&tree, limit)).await);
+/// });
+/// # } #[cfg(feature = "alloc")] test();
+/// ```
+pub fn scope_depth_limited<Fut, F>(max_depth: usize, make_fut: F) -> DepthLimited<Fut>
+where
+    Fut: Future,
+    F: FnOnce(DepthLimit) -> Fut,
+{
+    DepthLimited::new(1, max_depth, make_fut)
+}
+
+impl<Fut: Future> Future for DepthLimited<Fut> {
+    type Output = Result<Fut::Output, DepthExceeded>;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        if let Some(exceeded) = this.exceeded.take() {
+            return Poll::Ready(Err(exceeded));
+        }
+        let future = this.future.as_mut().as_pin_mut().expect("DepthLimited polled after completion");
+        let output = core::task::ready!(future.poll(cx));
+        this.future.set(None);
+        Poll::Ready(Ok(output))
+    }
+}
@@ -0,0 +1,48 @@
+use crate::ScopedBoxFuture;
+
+/// Sequentially runs each of the provided steps, re-borrowing `conn` for every step and threading
+/// an accumulator from one step to the next. Stops at the first step which returns an error.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use scoped_futures::{scope_each_step, ScopedBoxFuture, ScopedFutureExt};
+///
+/// pub struct Conn {
+///     log: Vec<&'static str>,
+/// }
+///
+/// type Step<'a> = dyn for<'b> Fn(&'b mut Conn, u8) -> ScopedBoxFuture<'a, 'b, Result<u8, &'static str>>;
+///
+/// futures::executor::block_on(async {
+///     let mut conn = Conn { log: vec![] };
+///
+///     let steps: [&Step; 3] = [
+///         &|conn, count| async move { conn.log.push("insert"); Ok(count + 1) }.scope_boxed(),
+///         &|conn, count| async move { conn.log.push("update"); Ok(count + 1) }.scope_boxed(),
+///         &|conn, count| async move { conn.log.push("delete"); Ok(count + 1) }.scope_boxed(),
+///     ];
+///     assert_eq!(Ok(3), scope_each_step(&steps, &mut conn, 0).await);
+///     assert_eq!(vec!["insert", "update", "delete"], conn.log);
+///
+///     let steps: [&Step; 3] = [
+///         &|conn, count| async move { conn.log.push("insert"); Ok(count + 1) }.scope_boxed(),
+///         &|_, _| async move { Err("conflict") }.scope_boxed(),
+///         &|conn, count| async move { conn.log.push("delete"); Ok(count + 1) }.scope_boxed(),
+///     ];
+///     assert_eq!(Err("conflict"), scope_each_step(&steps, &mut conn, 0).await);
+///     assert_eq!(vec!["insert", "update", "delete", "insert"], conn.log);
+/// });
+/// # } #[cfg(feature = "alloc")] test();
+/// ```
+pub async fn scope_each_step<'upper_bound, Conn, Acc, E, Step>(steps: &[Step], conn: &mut Conn, init: Acc) -> Result<Acc, E>
+where
+    Conn: ?Sized,
+    Step: for<'subject> Fn(&'subject mut Conn, Acc) -> ScopedBoxFuture<'upper_bound, 'subject, Result<Acc, E>>,
+{
+    let mut acc = init;
+    for step in steps {
+        acc = step(conn, acc).await?;
+    }
+    Ok(acc)
+}
@@ -0,0 +1,76 @@
+use core::{
+    future::Future,
+    panic::Location,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_eager`](crate::ScopedFutureExt::scope_eager) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use scoped_futures::ScopedFutureExt;
+    ///
+    /// let mut count = 0;
+    /// let output = async { count += 1; count }.scope_eager().now();
+    /// assert_eq!(1, output);
+    ///
+    /// let output = futures::executor::block_on(async { count += 1; count }.scope_eager());
+    /// assert_eq!(2, output);
+    /// # } test();
+    /// ```
+    ///
+    /// Polling a future which is not immediately ready panics when this crate is built with debug assertions, and is pending otherwise.
+    /// The panic message reports where the future was constructed.
+    /// ```
+    /// # fn test() {
+    /// use core::{future::Future, panic::Location, pin::pin, task::{Context, Waker}};
+    /// use scoped_futures::ScopedFutureExt;
+    ///
+    /// let (future, constructed) = (futures::future::pending::<()>().scope_eager(), Location::caller());
+    /// let poll = std::panic::catch_unwind(move || pin!(future).poll(&mut Context::from_waker(Waker::noop())).is_pending());
+    /// match poll {
+    ///     Ok(pending) => assert!(pending),
+    ///     Err(panic) => assert!(panic.downcast::<String>().unwrap().contains(&format!("{}:{}:", constructed.file(), constructed.line()))),
+    /// }
+    /// # } test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Eager<Fut> {
+        #[pin]
+        future: Fut,
+        location: &'static Location<'static>,
+    }
+}
+
+impl<Fut: Future> Eager<Fut> {
+    #[track_caller]
+    pub(crate) fn new(future: Fut) -> Self {
+        Self { future, location: Location::caller() }
+    }
+
+    /// Polls the inner future exactly once and returns its output.
+    ///
+    /// # Panics
+    /// Panics if the inner future is not ready on its first poll.
+    pub fn now(self) -> Fut::Output {
+        let location = self.location;
+        let future = core::pin::pin!(self.future);
+        match future.poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("eager future constructed at {location} was not ready on its first poll"),
+        }
+    }
+}
+
+impl<Fut: Future> Future for Eager<Fut> {
+    type Output = Fut::Output;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let poll = this.future.poll(cx);
+        debug_assert!(poll.is_ready(), "eager future constructed at {} was not ready on its first poll", this.location);
+        poll
+    }
+}
@@ -0,0 +1,45 @@
+use alloc::boxed::Box;
+use core::{future::Future, pin::Pin};
+
+use crate::ScopedBoxFuture;
+
+/// Converts a scoped future which only borrows from `state` into a `'static` boxed future by moving `state` into it.
+///
+/// This is useful for handing scoped work to `'static` requiring pipelines, such as logging or telemetry exporters.
+/// Because the callback is higher-ranked over the borrow of `state` and its output must be `'static`,
+/// the scoped future cannot borrow anything other than the moved in state.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use scoped_futures::{scope_erase_to_static, ScopedFutureExt};
+///
+/// pub struct Batch {
+///     lines: Vec<String>,
+/// }
+///
+/// let batch = Batch { lines: vec![String::from("a"), String::from("b")] };
+/// let future = scope_erase_to_static(batch, |batch| async move {
+///     batch.lines.push(String::from("c"));
+///     match batch.lines.len() {
+///         0 => Err("empty batch"),
+///         len => Ok(len),
+///     }
+/// }.scope_boxed());
+///
+/// let exported = std::thread::spawn(move || futures::executor::block_on(future)).join().unwrap();
+/// assert_eq!(Ok(3), exported);
+/// # } #[cfg(feature = "alloc")] test();
+/// ```
+pub fn scope_erase_to_static<State, T, E, F>(state: State, f: F) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'static>>
+where
+    State: Send + 'static,
+    T: 'static,
+    E: 'static,
+    F: for<'subject> FnOnce(&'subject mut State) -> ScopedBoxFuture<'static, 'subject, Result<T, E>> + Send + 'static,
+{
+    Box::pin(async move {
+        let mut state = state;
+        f(&mut state).await
+    })
+}
@@ -0,0 +1,89 @@
+use crate::spin_lock::SpinLock;
+use alloc::sync::Arc;
+use core::{
+    fmt,
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_with_external_waker`](crate::ScopedFutureExt::scope_with_external_waker) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use core::{sync::atomic::{AtomicBool, Ordering}, task::Poll};
+    /// use scoped_futures::{ScopedBoxFuture, ScopedFutureExt};
+    ///
+    /// // stands in for a completion flag set by an out-of-band event, which has no way of waking the task itself
+    /// let completed = &AtomicBool::new(false);
+    /// let (future, handle) = futures::future::poll_fn(|_| match completed.load(Ordering::Acquire) {
+    ///     true => Poll::Ready("done"),
+    ///     false => Poll::Pending,
+    /// })
+    /// .scope_with_external_waker();
+    /// let future: ScopedBoxFuture<'_, '_, &str> = future.scope_boxed();
+    ///
+    /// let output = std::thread::scope(|scope| {
+    ///     scope.spawn(move || {
+    ///         completed.store(true, Ordering::Release);
+    ///         handle.wake();
+    ///     });
+    ///     futures::executor::block_on(future)
+    /// });
+    /// assert_eq!("done", output);
+    /// # } #[cfg(feature = "alloc")] test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct ExternallyWoken<'subject, Fut> {
+        #[pin]
+        future: Fut,
+        waker: Arc<SpinLock<Option<Waker>>>,
+        scope: PhantomData<&'subject ()>,
+    }
+}
+
+/// A handle which can force the future it was created with to be polled again.
+/// The handle cannot outlive the scope of the future, but may be sent to other threads within it.
+#[derive(Clone)]
+pub struct WakerHandle<'subject> {
+    waker: Arc<SpinLock<Option<Waker>>>,
+    scope: PhantomData<&'subject ()>,
+}
+
+impl fmt::Debug for WakerHandle<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WakerHandle").finish_non_exhaustive()
+    }
+}
+
+impl<'subject, Fut: 'subject> ExternallyWoken<'subject, Fut> {
+    pub(crate) fn new(future: Fut) -> (Self, WakerHandle<'subject>) {
+        let waker = Arc::new(SpinLock::new(None));
+        (Self { future, waker: waker.clone(), scope: PhantomData }, WakerHandle { waker, scope: PhantomData })
+    }
+}
+
+impl WakerHandle<'_> {
+    /// Wakes the task which last polled the future, if it has been polled.
+    pub fn wake(&self) {
+        if let Some(waker) = self.waker.with(|waker| waker.clone()) {
+            waker.wake();
+        }
+    }
+}
+
+impl<Fut: Future> Future for ExternallyWoken<'_, Fut> {
+    type Output = Fut::Output;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        this.waker.with(|waker| {
+            if !waker.as_ref().is_some_and(|waker| waker.will_wake(cx.waker())) {
+                *waker = Some(cx.waker().clone());
+            }
+        });
+        this.future.poll(cx)
+    }
+}
@@ -0,0 +1,52 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_extract`](crate::ScopedFutureExt::scope_extract) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use scoped_futures::ScopedFutureExt;
+    ///
+    /// pub struct Page<'a> {
+    ///     rows: Vec<&'a str>,
+    /// }
+    ///
+    /// async fn select<'a>(table: &'a [&'a str], prefix: &str) -> Page<'a> {
+    ///     Page { rows: table.iter().copied().filter(|row| row.starts_with(prefix)).collect() }
+    /// }
+    ///
+    /// let summary: (usize, String) = futures::executor::block_on(async {
+    ///     let table = vec!["ann", "bob", "abe"];
+    ///     select(&table, "a").scope_extract(|page| (page.rows.len(), page.rows.join(","))).await
+    /// });
+    /// assert_eq!((2, String::from("ann,abe")), summary);
+    /// # } test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Extract<Fut, F> {
+        #[pin]
+        future: Fut,
+        f: Option<F>,
+    }
+}
+
+impl<Fut, F> Extract<Fut, F> {
+    pub(crate) fn new(future: Fut, f: F) -> Self {
+        Self { future, f: Some(f) }
+    }
+}
+
+impl<U, Fut: Future, F: FnOnce(&Fut::Output) -> U> Future for Extract<Fut, F> {
+    type Output = U;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let output = core::task::ready!(this.future.poll(cx));
+        let f = this.f.take().expect("Extract polled after completion");
+        Poll::Ready(f(&output))
+    }
+}
@@ -0,0 +1,30 @@
+use crate::spin_lock::SpinLock;
+use alloc::{sync::Arc, task::Wake, vec::Vec};
+use core::task::Waker;
+
+/// Waker handed to a shared future or stream which wakes every task waiting on it,
+/// so that it keeps making progress when the task which last polled it is dropped.
+#[derive(Default)]
+pub(crate) struct FanOutWaker {
+    wakers: SpinLock<Vec<Waker>>,
+}
+
+impl FanOutWaker {
+    pub(crate) fn register(&self, waker: &Waker) {
+        self.wakers.with(|wakers| {
+            if !wakers.iter().any(|registered| registered.will_wake(waker)) {
+                wakers.push(waker.clone());
+            }
+        })
+    }
+}
+
+impl Wake for FanOutWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref()
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.wakers.with(core::mem::take).into_iter().for_each(Waker::wake);
+    }
+}
@@ -0,0 +1,82 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project_lite::pin_project! {
+    /// A future which awaits an async finalizer after its inner future completes or is cancelled,
+    /// simulating async drop within a scope.
+    ///
+    /// Resolves to the output of the inner future once the finalizer has completed, or [`None`] if the inner future was
+    /// [cancelled](ScopedFinalized::cancel).
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use core::{cell::RefCell, future::Future, task::{Context, Poll}};
+    /// use scoped_futures::ScopedFutureExt;
+    ///
+    /// async fn transaction(log: &RefCell<Vec<&'static str>>, commit: bool) -> &'static str {
+    ///     if !commit {
+    ///         futures::future::pending::<()>().await;
+    ///     }
+    ///     log.borrow_mut().push("commit");
+    ///     "committed"
+    /// }
+    ///
+    /// async fn release(log: &RefCell<Vec<&'static str>>) {
+    ///     log.borrow_mut().push("release");
+    /// }
+    ///
+    /// let log = RefCell::new(vec![]);
+    /// let transaction = |commit| transaction(&log, commit).scope_finalized(release(&log));
+    ///
+    /// let output = futures::executor::block_on(transaction(true));
+    /// assert_eq!(Some("committed"), output);
+    ///
+    /// let mut future = Box::pin(transaction(false));
+    /// assert_eq!(Poll::Pending, future.as_mut().poll(&mut Context::from_waker(&futures::task::noop_waker())));
+    /// future.as_mut().cancel();
+    /// assert_eq!(None, futures::executor::block_on(future));
+    ///
+    /// assert_eq!(vec!["commit", "release", "release"], log.into_inner());
+    /// # } test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct ScopedFinalized<Fut: Future, Fin> {
+        #[pin]
+        future: Option<Fut>,
+        output: Option<Fut::Output>,
+        #[pin]
+        finalizer: Option<Fin>,
+    }
+}
+
+impl<Fut: Future, Fin: Future<Output = ()>> ScopedFinalized<Fut, Fin> {
+    /// Creates a future which awaits `finalizer` after `future` completes or is cancelled.
+    pub fn new(future: Fut, finalizer: Fin) -> Self {
+        Self { future: Some(future), output: None, finalizer: Some(finalizer) }
+    }
+
+    /// Drops the inner future if it has not yet completed. The finalizer still runs the next time this future is polled.
+    pub fn cancel(self: Pin<&mut Self>) {
+        self.project().future.set(None);
+    }
+}
+
+impl<Fut: Future, Fin: Future<Output = ()>> Future for ScopedFinalized<Fut, Fin> {
+    type Output = Option<Fut::Output>;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        if let Some(future) = this.future.as_mut().as_pin_mut() {
+            *this.output = Some(core::task::ready!(future.poll(cx)));
+            this.future.set(None);
+        }
+        if let Some(finalizer) = this.finalizer.as_mut().as_pin_mut() {
+            core::task::ready!(finalizer.poll(cx));
+            this.finalizer.set(None);
+        }
+        Poll::Ready(this.output.take())
+    }
+}
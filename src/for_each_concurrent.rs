@@ -0,0 +1,77 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    future::Future,
+    num::NonZeroUsize,
+    pin::Pin,
+    slice::IterMut,
+    task::{Context, Poll},
+};
+
+/// Future for the [`scope_for_each_concurrent`] function.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ForEachConcurrent<'subject, T, F, Fut> {
+    items: IterMut<'subject, T>,
+    f: F,
+    limit: Option<NonZeroUsize>,
+    in_flight: Vec<Pin<Box<Fut>>>,
+}
+
+/// Runs `f` over each element of the borrowed `slice`, with at most `limit` futures in flight at once.
+/// A `limit` of [`None`] or zero runs every future concurrently.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use core::cell::Cell;
+/// use scoped_futures::scope_for_each_concurrent;
+/// # use scoped_futures::yield_now;
+///
+/// let mut rows = vec![1, 2, 3, 4, 5];
+/// let in_flight = Cell::new(0);
+/// let max_in_flight = Cell::new(0);
+/// futures::executor::block_on(scope_for_each_concurrent(&mut rows, 2, |row| {
+///     let (in_flight, max_in_flight) = (&in_flight, &max_in_flight);
+///     async move {
+///         in_flight.set(in_flight.get() + 1);
+///         max_in_flight.set(max_in_flight.get().max(in_flight.get()));
+///         yield_now().await;
+///         *row *= 10;
+///         in_flight.set(in_flight.get() - 1);
+///     }
+/// }));
+/// assert_eq!(vec![10, 20, 30, 40, 50], rows);
+/// assert_eq!(2, max_in_flight.get());
+/// # } #[cfg(feature = "alloc")] test();
+/// ```
+pub fn scope_for_each_concurrent<'subject, T, F, Fut>(slice: &'subject mut [T], limit: impl Into<Option<usize>>, f: F) -> ForEachConcurrent<'subject, T, F, Fut>
+where
+    F: FnMut(&'subject mut T) -> Fut,
+    Fut: Future<Output = ()> + 'subject,
+{
+    ForEachConcurrent { items: slice.iter_mut(), f, limit: limit.into().and_then(NonZeroUsize::new), in_flight: Vec::new() }
+}
+
+impl<T, F, Fut> Unpin for ForEachConcurrent<'_, T, F, Fut> {}
+
+impl<'subject, T, F: FnMut(&'subject mut T) -> Fut, Fut: Future<Output = ()>> Future for ForEachConcurrent<'subject, T, F, Fut> {
+    type Output = ();
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        loop {
+            while this.limit.is_none_or(|limit| this.in_flight.len() < limit.get()) {
+                match this.items.next() {
+                    Some(item) => this.in_flight.push(Box::pin((this.f)(item))),
+                    None => break,
+                }
+            }
+            let before = this.in_flight.len();
+            this.in_flight.retain_mut(|future| future.as_mut().poll(cx).is_pending());
+            if this.in_flight.is_empty() && this.items.len() == 0 {
+                return Poll::Ready(());
+            }
+            if this.in_flight.len() == before {
+                return Poll::Pending;
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use alloc::{format, string::String};
+use core::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_display`](crate::ScopedFutureExt::scope_display) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use scoped_futures::ScopedFutureExt;
+    ///
+    /// async fn max(rows: &[f64]) -> &f64 {
+    ///     rows.iter().max_by(|a, b| a.total_cmp(b)).unwrap()
+    /// }
+    ///
+    /// let rows = vec![1.5, 2.25, 0.5];
+    /// assert_eq!("2.25", futures::executor::block_on(max(&rows).scope_display()));
+    /// # } #[cfg(feature = "alloc")] test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct DisplayString<Fut> {
+        #[pin]
+        future: Fut,
+    }
+}
+
+impl<Fut> DisplayString<Fut> {
+    pub(crate) fn new(future: Fut) -> Self {
+        Self { future }
+    }
+}
+
+impl<Fut: Future<Output: fmt::Display>> Future for DisplayString<Fut> {
+    type Output = String;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().future.poll(cx).map(|output| format!("{output}"))
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_debug`](crate::ScopedFutureExt::scope_debug) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use scoped_futures::ScopedFutureExt;
+    ///
+    /// async fn evens(rows: &[u8]) -> Vec<&u8> {
+    ///     rows.iter().filter(|row| **row % 2 == 0).collect()
+    /// }
+    ///
+    /// let rows = vec![1, 2, 3, 4];
+    /// assert_eq!("[2, 4]", futures::executor::block_on(evens(&rows).scope_debug()));
+    /// # } #[cfg(feature = "alloc")] test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct DebugString<Fut> {
+        #[pin]
+        future: Fut,
+    }
+}
+
+impl<Fut> DebugString<Fut> {
+    pub(crate) fn new(future: Fut) -> Self {
+        Self { future }
+    }
+}
+
+impl<Fut: Future<Output: fmt::Debug>> Future for DebugString<Fut> {
+    type Output = String;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().future.poll(cx).map(|output| format!("{output:?}"))
+    }
+}
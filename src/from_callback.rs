@@ -0,0 +1,95 @@
+use alloc::{boxed::Box, rc::Rc};
+use core::{
+    cell::RefCell,
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+struct State<T> {
+    value: Option<T>,
+    waker: Option<Waker>,
+    closed: bool,
+}
+
+struct Completion<T> {
+    state: Rc<RefCell<State<T>>>,
+}
+
+impl<T> Completion<T> {
+    fn complete(self, value: T) {
+        self.state.borrow_mut().value = Some(value);
+    }
+}
+
+impl<T> Drop for Completion<T> {
+    fn drop(&mut self) {
+        let mut state = self.state.borrow_mut();
+        state.closed = true;
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Future for the [`scoped_from_callback`] function.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct FromCallback<'subject, T, R> {
+    register: Option<R>,
+    state: Rc<RefCell<State<T>>>,
+    scope: PhantomData<&'subject ()>,
+}
+
+/// Creates a future which hands a completion callback to `register` on its first poll and resolves once that callback is called.
+/// Resolves to [`None`] if the callback is dropped without being called.
+///
+/// This allows completions from callback based APIs, such as FFI, to borrow data for `'subject`.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use core::{cell::RefCell, future::Future, task::{Context, Poll}};
+/// use scoped_futures::scoped_from_callback;
+///
+/// // stands in for an external API which stores a completion callback to call later
+/// let pending: RefCell<Option<Box<dyn FnOnce(u8)>>> = RefCell::new(None);
+/// let mut future = Box::pin(scoped_from_callback(|callback| *pending.borrow_mut() = Some(callback)));
+///
+/// let waker = futures::task::noop_waker();
+/// let mut cx = Context::from_waker(&waker);
+/// assert!(future.as_mut().poll(&mut cx).is_pending());
+///
+/// let complete = pending.borrow_mut().take().unwrap();
+/// complete(7);
+/// assert_eq!(Poll::Ready(Some(7)), future.as_mut().poll(&mut cx));
+/// # } #[cfg(feature = "alloc")] test();
+/// ```
+pub fn scoped_from_callback<'subject, T, R>(register: R) -> FromCallback<'subject, T, R>
+where
+    T: 'subject,
+    R: FnOnce(Box<dyn FnOnce(T) + 'subject>),
+{
+    FromCallback { register: Some(register), state: Rc::new(RefCell::new(State { value: None, waker: None, closed: false })), scope: PhantomData }
+}
+
+impl<T, R> Unpin for FromCallback<'_, T, R> {}
+
+impl<'subject, T: 'subject, R: FnOnce(Box<dyn FnOnce(T) + 'subject>)> Future for FromCallback<'subject, T, R> {
+    type Output = Option<T>;
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Some(register) = self.register.take() {
+            let completion = Completion { state: self.state.clone() };
+            register(Box::new(move |value| completion.complete(value)));
+        }
+        let mut state = self.state.borrow_mut();
+        if let Some(value) = state.value.take() {
+            return Poll::Ready(Some(value));
+        }
+        if state.closed {
+            return Poll::Ready(None);
+        }
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
@@ -0,0 +1,43 @@
+use core::{
+    future::Future,
+    hash::{BuildHasher, Hash},
+};
+use std::collections::{hash_map::Entry, HashMap};
+
+/// Returns the value stored under `key` in the borrowed `map`, first awaiting `init` and inserting its output
+/// if the entry is absent. The returned reference borrows from `map` for as long as `map` itself is borrowed.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use scoped_futures::scope_get_or_insert_with;
+/// use std::collections::HashMap;
+///
+/// async fn load<'a>(db: &'a [(&'a str, u8)], key: &str) -> u8 {
+///     db.iter().find(|(name, _)| *name == key).map_or(0, |(_, value)| *value)
+/// }
+///
+/// let db = vec![("ann", 1), ("bob", 2)];
+/// let mut cache = HashMap::from([("ann", 10)]);
+/// futures::executor::block_on(async {
+///     assert_eq!(&10, scope_get_or_insert_with(&mut cache, "ann", |key| load(&db, key)).await);
+///     assert_eq!(&2, scope_get_or_insert_with(&mut cache, "bob", |key| load(&db, key)).await);
+/// });
+/// assert_eq!(HashMap::from([("ann", 10), ("bob", 2)]), cache);
+/// # } #[cfg(feature = "std")] test();
+/// ```
+pub async fn scope_get_or_insert_with<K, V, S, F, Fut>(map: &mut HashMap<K, V, S>, key: K, init: F) -> &mut V
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+    F: FnOnce(&K) -> Fut,
+    Fut: Future<Output = V>,
+{
+    match map.entry(key) {
+        Entry::Occupied(entry) => entry.into_mut(),
+        Entry::Vacant(entry) => {
+            let value = init(entry.key()).await;
+            entry.insert(value)
+        }
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(feature = "alloc")]
+use alloc::borrow::{Cow, ToOwned};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A borrowed guard which can be converted into an owned equivalent that remains usable after its scope ends.
+///
+/// Implementations must transfer ownership of the guarded data rather than copying a reference to it,
+/// e.g. by detaching a connection from its pool or by cloning the guarded value. Whether the owned guard
+/// actually outlives the scope is still checked by the compiler: an `Owned` type which borrows the guard's
+/// lifetime is bounded by the scope like any other borrow.
+pub trait IntoOwnedGuard {
+    /// The owned equivalent of this guard.
+    type Owned;
+
+    /// Converts this guard into its owned equivalent.
+    fn into_owned_guard(self) -> Self::Owned;
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ToOwned + ?Sized> IntoOwnedGuard for &T {
+    type Owned = T::Owned;
+    fn into_owned_guard(self) -> T::Owned {
+        self.to_owned()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ToOwned + ?Sized> IntoOwnedGuard for Cow<'_, B> {
+    type Owned = B::Owned;
+    fn into_owned_guard(self) -> B::Owned {
+        self.into_owned()
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_guard_into_owned`](crate::ScopedFutureExt::scope_guard_into_owned) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use scoped_futures::{IntoOwnedGuard, ScopedFutureExt};
+    ///
+    /// pub struct Pool {
+    ///     idle: Vec<String>,
+    /// }
+    ///
+    /// /// A connection checked out from a borrowed pool.
+    /// pub struct Checkout<'a> {
+    ///     pool: &'a mut Pool,
+    ///     conn: String,
+    /// }
+    ///
+    /// /// A connection detached from its pool.
+    /// pub struct Detached(String);
+    ///
+    /// impl IntoOwnedGuard for Checkout<'_> {
+    ///     type Owned = Detached;
+    ///     fn into_owned_guard(self) -> Detached {
+    ///         Detached(self.conn)
+    ///     }
+    /// }
+    ///
+    /// async fn checkout(pool: &mut Pool) -> Checkout<'_> {
+    ///     let conn = pool.idle.pop().unwrap();
+    ///     Checkout { pool, conn }
+    /// }
+    ///
+    /// let detached = futures::executor::block_on(async {
+    ///     let mut pool = Pool { idle: vec![String::from("conn-1")] };
+    ///     let detached = checkout(&mut pool).scope_guard_into_owned().await;
+    ///     assert!(pool.idle.is_empty());
+    ///     detached
+    /// });
+    /// assert_eq!("conn-1", detached.0);
+    /// # } test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct GuardIntoOwned<Fut> {
+        #[pin]
+        future: Fut,
+    }
+}
+
+impl<Fut> GuardIntoOwned<Fut> {
+    pub(crate) fn new(future: Fut) -> Self {
+        Self { future }
+    }
+}
+
+impl<Fut: Future<Output: IntoOwnedGuard>> Future for GuardIntoOwned<Fut> {
+    type Output = <Fut::Output as IntoOwnedGuard>::Owned;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().future.poll(cx).map(IntoOwnedGuard::into_owned_guard)
+    }
+}
@@ -0,0 +1,103 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use crate::Timer;
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_with_heartbeat`](crate::ScopedFutureExt::scope_with_heartbeat) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use core::{cell::Cell, time::Duration};
+    /// use scoped_futures::ScopedFutureExt;
+    /// # use scoped_futures::yield_now;
+    ///
+    /// // every sleep elapses after the task yields once
+    /// let timer = |_: Duration| yield_now();
+    /// let pings = Cell::new(0);
+    /// let output = futures::executor::block_on(
+    ///     async {
+    ///         for _ in 0..4 {
+    ///             yield_now().await;
+    ///         }
+    ///         "committed"
+    ///     }
+    ///     .scope_with_heartbeat(timer, Duration::from_secs(1), || async { pings.set(pings.get() + 1) }),
+    /// );
+    /// assert_eq!("committed", output);
+    /// assert_eq!(3, pings.get());
+    ///
+    /// // sleeps which are already elapsed still run one beat per poll
+    /// let elapsed = |_: Duration| futures::future::ready(());
+    /// pings.set(0);
+    /// let output = futures::executor::block_on(
+    ///     async {
+    ///         for _ in 0..3 {
+    ///             yield_now().await;
+    ///         }
+    ///         "committed"
+    ///     }
+    ///     .scope_with_heartbeat(elapsed, Duration::ZERO, || async { pings.set(pings.get() + 1) }),
+    /// );
+    /// assert_eq!("committed", output);
+    /// assert_eq!(3, pings.get());
+    /// # } test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct WithHeartbeat<Fut, T: Timer, F, Beat> {
+        #[pin]
+        future: Fut,
+        timer: T,
+        interval: Duration,
+        beat_fn: F,
+        #[pin]
+        sleep: Option<T::Sleep>,
+        #[pin]
+        beat: Option<Beat>,
+    }
+}
+
+impl<Fut, T: Timer, F, Beat> WithHeartbeat<Fut, T, F, Beat> {
+    pub(crate) fn new(future: Fut, timer: T, interval: Duration, beat_fn: F) -> Self {
+        let sleep = Some(timer.sleep(interval));
+        Self { future, timer, interval, beat_fn, sleep, beat: None }
+    }
+}
+
+impl<Fut: Future, T: Timer, F: FnMut() -> Beat, Beat: Future<Output = ()>> Future for WithHeartbeat<Fut, T, F, Beat> {
+    type Output = Fut::Output;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        if let Poll::Ready(output) = this.future.poll(cx) {
+            return Poll::Ready(output);
+        }
+        let mut beaten = false;
+        loop {
+            if let Some(beat) = this.beat.as_mut().as_pin_mut() {
+                if beat.poll(cx).is_pending() {
+                    return Poll::Pending;
+                }
+                this.beat.set(None);
+                this.sleep.set(Some(this.timer.sleep(*this.interval)));
+                beaten = true;
+            }
+            if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
+                if sleep.poll(cx).is_pending() {
+                    return Poll::Pending;
+                }
+                this.sleep.set(None);
+                this.beat.set(Some((this.beat_fn)()));
+                // run at most one beat per poll, so sleeps which are immediately ready do not starve the executor
+                if beaten {
+                    cx.waker().wake_by_ref();
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use std::time::Instant;
+
+/// A sink for poll statistics of a [`Future`], such as an `hdrhistogram` or a prometheus histogram.
+pub trait Record {
+    /// Records a single completed future which was polled `polls` times with `elapsed` time between its first poll and its completion.
+    fn record(&mut self, polls: u64, elapsed: Duration);
+}
+
+impl<R: Record + ?Sized> Record for &mut R {
+    fn record(&mut self, polls: u64, elapsed: Duration) {
+        (**self).record(polls, elapsed)
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_histogram`](crate::ScopedFutureExt::scope_histogram) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use core::time::Duration;
+    /// use scoped_futures::{Record, ScopedFutureExt};
+    /// # use scoped_futures::yield_now;
+    ///
+    /// #[derive(Default)]
+    /// pub struct MockHistogram {
+    ///     samples: Vec<(u64, Duration)>,
+    /// }
+    ///
+    /// impl Record for MockHistogram {
+    ///     fn record(&mut self, polls: u64, elapsed: Duration) {
+    ///         self.samples.push((polls, elapsed));
+    ///     }
+    /// }
+    ///
+    /// futures::executor::block_on(async {
+    ///     let mut histogram = MockHistogram::default();
+    ///     let mut count = 0;
+    ///     async {
+    ///         count += 1;
+    ///         yield_now().await;
+    ///         count += 1;
+    ///     }.scope_histogram(&mut histogram).await;
+    ///     assert_eq!(2, count);
+    ///     assert_eq!(1, histogram.samples.len());
+    ///     assert_eq!(2, histogram.samples[0].0);
+    /// });
+    /// # } #[cfg(feature = "std")] test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct WithHistogram<Fut, H> {
+        #[pin]
+        future: Fut,
+        histogram: H,
+        polls: u64,
+        started: Option<Instant>,
+    }
+}
+
+impl<Fut, H> WithHistogram<Fut, H> {
+    pub(crate) fn new(future: Fut, histogram: H) -> Self {
+        Self { future, histogram, polls: 0, started: None }
+    }
+}
+
+impl<Fut: Future, H: Record> Future for WithHistogram<Fut, H> {
+    type Output = Fut::Output;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let started = *this.started.get_or_insert_with(Instant::now);
+        *this.polls += 1;
+        let output = core::task::ready!(this.future.poll(cx));
+        this.histogram.record(*this.polls, started.elapsed());
+        Poll::Ready(output)
+    }
+}
@@ -0,0 +1,98 @@
+use core::{
+    cell::Cell,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Records whether a side effect has already been applied, e.g. in an idempotency key table.
+pub trait IdempotencyGuard {
+    /// Returns whether the side effect has already been applied.
+    fn is_applied(&self) -> bool;
+
+    /// Records that the side effect has been applied.
+    fn mark_applied(&self);
+}
+
+impl IdempotencyGuard for Cell<bool> {
+    fn is_applied(&self) -> bool {
+        self.get()
+    }
+
+    fn mark_applied(&self) {
+        self.set(true)
+    }
+}
+
+impl<G: IdempotencyGuard + ?Sized> IdempotencyGuard for &G {
+    fn is_applied(&self) -> bool {
+        (**self).is_applied()
+    }
+
+    fn mark_applied(&self) {
+        (**self).mark_applied()
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_idempotent`](crate::ScopedFutureExt::scope_idempotent) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use core::cell::Cell;
+    /// use scoped_futures::ScopedFutureExt;
+    ///
+    /// async fn credit(balance: &mut u32, amount: u32) {
+    ///     *balance += amount;
+    /// }
+    ///
+    /// async fn commit(attempt: u8) -> Result<(), &'static str> {
+    ///     if attempt == 0 { Err("serialization failure") } else { Ok(()) }
+    /// }
+    ///
+    /// let mut balance = 0;
+    /// let credited = Cell::new(false);
+    /// let mut applied = vec![];
+    /// futures::executor::block_on(async {
+    ///     for attempt in 0.. {
+    ///         applied.push(credit(&mut balance, 10).scope_idempotent(&credited).await.is_some());
+    ///         if commit(attempt).await.is_ok() {
+    ///             break;
+    ///         }
+    ///     }
+    /// });
+    /// assert_eq!(10, balance);
+    /// assert_eq!(vec![true, false], applied);
+    /// # } test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Idempotent<Fut, G> {
+        #[pin]
+        future: Fut,
+        guard: G,
+        checked: bool,
+    }
+}
+
+impl<Fut, G> Idempotent<Fut, G> {
+    pub(crate) fn new(future: Fut, guard: G) -> Self {
+        Self { future, guard, checked: false }
+    }
+}
+
+impl<Fut: Future, G: IdempotencyGuard> Future for Idempotent<Fut, G> {
+    type Output = Option<Fut::Output>;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        if !*this.checked {
+            if this.guard.is_applied() {
+                return Poll::Ready(None);
+            }
+            *this.checked = true;
+        }
+        let output = core::task::ready!(this.future.poll(cx));
+        this.guard.mark_applied();
+        Poll::Ready(Some(output))
+    }
+}
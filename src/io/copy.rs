@@ -0,0 +1,78 @@
+use alloc::{boxed::Box, vec};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_io::{AsyncRead, AsyncWrite};
+use std::io;
+
+const BUFFER_SIZE: usize = 8 * 1024;
+
+/// Future for the [`scope_io_copy`] function.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct IoCopy<'subject, R: ?Sized, W: ?Sized> {
+    reader: &'subject mut R,
+    writer: &'subject mut W,
+    buffer: Box<[u8]>,
+    pos: usize,
+    cap: usize,
+    amt: u64,
+    read_done: bool,
+}
+
+/// Copies all bytes from the borrowed `reader` into the borrowed `writer`, resolving to the number of bytes copied.
+/// This is the scoped counterpart to `futures::io::copy`.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "io")] fn test() {
+/// use futures::io::Cursor;
+/// use scoped_futures::scope_io_copy;
+///
+/// futures::executor::block_on(async {
+///     let mut reader = Cursor::new(&b"hello world"[..]);
+///     let mut writer = Cursor::new(vec![]);
+///     let copied = scope_io_copy(&mut reader, &mut writer).await.unwrap();
+///     assert_eq!(11, copied);
+///     assert_eq!(b"hello world", &writer.get_ref()[..]);
+/// });
+/// # } #[cfg(feature = "io")] test();
+/// ```
+pub fn scope_io_copy<'subject, R, W>(reader: &'subject mut R, writer: &'subject mut W) -> IoCopy<'subject, R, W>
+where
+    R: AsyncRead + Unpin + ?Sized,
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    IoCopy { reader, writer, buffer: vec![0; BUFFER_SIZE].into_boxed_slice(), pos: 0, cap: 0, amt: 0, read_done: false }
+}
+
+impl<R: AsyncRead + Unpin + ?Sized, W: AsyncWrite + Unpin + ?Sized> Future for IoCopy<'_, R, W> {
+    type Output = io::Result<u64>;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        loop {
+            if this.pos == this.cap && !this.read_done {
+                let n = core::task::ready!(Pin::new(&mut *this.reader).poll_read(cx, &mut this.buffer))?;
+                if n == 0 {
+                    this.read_done = true;
+                } else {
+                    this.pos = 0;
+                    this.cap = n;
+                }
+            }
+            while this.pos < this.cap {
+                let n = core::task::ready!(Pin::new(&mut *this.writer).poll_write(cx, &this.buffer[this.pos..this.cap]))?;
+                if n == 0 {
+                    return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+                }
+                this.pos += n;
+                this.amt += n as u64;
+            }
+            if this.read_done {
+                core::task::ready!(Pin::new(&mut *this.writer).poll_flush(cx))?;
+                return Poll::Ready(Ok(this.amt));
+            }
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod copy;
+mod read_to_end;
+
+pub use copy::{scope_io_copy, IoCopy};
+pub use read_to_end::{scope_read_to_end, ReadToEnd};
@@ -0,0 +1,60 @@
+use alloc::vec::Vec;
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_io::AsyncRead;
+use std::io;
+
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// Future for the [`scope_read_to_end`] function.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReadToEnd<'subject, R: ?Sized> {
+    reader: &'subject mut R,
+    buffer: Vec<u8>,
+}
+
+/// Reads all bytes from the borrowed `reader` until EOF, resolving to the bytes read.
+/// This is the scoped counterpart to `futures::io::AsyncReadExt::read_to_end`.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "io")] fn test() {
+/// use futures::io::Cursor;
+/// use scoped_futures::scope_read_to_end;
+///
+/// futures::executor::block_on(async {
+///     let mut reader = Cursor::new(&b"hello world"[..]);
+///     assert_eq!(b"hello world", &scope_read_to_end(&mut reader).await.unwrap()[..]);
+///     assert_eq!(11, reader.position());
+///     let mut reader = Cursor::new(&b""[..]);
+///     assert!(scope_read_to_end(&mut reader).await.unwrap().is_empty());
+/// });
+/// # } #[cfg(feature = "io")] test();
+/// ```
+pub fn scope_read_to_end<R: AsyncRead + Unpin + ?Sized>(reader: &mut R) -> ReadToEnd<'_, R> {
+    ReadToEnd { reader, buffer: Vec::new() }
+}
+
+impl<R: AsyncRead + Unpin + ?Sized> Future for ReadToEnd<'_, R> {
+    type Output = io::Result<Vec<u8>>;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        loop {
+            let len = this.buffer.len();
+            this.buffer.resize(len + CHUNK_SIZE, 0);
+            let poll = Pin::new(&mut *this.reader).poll_read(cx, &mut this.buffer[len..]);
+            let read = if let Poll::Ready(Ok(n)) = poll { n } else { 0 };
+            this.buffer.truncate(len + read);
+            match poll {
+                Poll::Ready(Ok(0)) => return Poll::Ready(Ok(core::mem::take(&mut this.buffer))),
+                Poll::Ready(Ok(_)) => {}
+                Poll::Ready(Err(error)) if error.kind() == io::ErrorKind::Interrupted => {}
+                Poll::Ready(Err(error)) => return Poll::Ready(Err(error)),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use alloc::boxed::Box;
+
+use crate::ScopedBoxFuture;
+
+/// A middleware which wraps a boxed scoped future, e.g. to check permissions or log around it.
+///
+/// This is implemented for every [`FnOnce`] from the wrapped future to the wrapping one, and layers are applied
+/// with [`scope_layered`](crate::ScopedFutureExt::scope_layered).
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use scoped_futures::{ScopedBoxFuture, ScopedBoxLayer, ScopedFutureExt};
+/// use std::sync::Mutex;
+///
+/// type Log = Mutex<Vec<&'static str>>;
+///
+/// fn auth<'a>(log: &'a Log) -> impl FnOnce(ScopedBoxFuture<'a, 'a, u8>) -> ScopedBoxFuture<'a, 'a, u8> + Send {
+///     move |next| async move {
+///         log.lock().unwrap().push("authorized");
+///         next.await
+///     }.scope_boxed()
+/// }
+///
+/// fn logging<'a>(log: &'a Log) -> impl FnOnce(ScopedBoxFuture<'a, 'a, u8>) -> ScopedBoxFuture<'a, 'a, u8> + Send {
+///     move |next| async move {
+///         log.lock().unwrap().push("started");
+///         let output = next.await;
+///         log.lock().unwrap().push("finished");
+///         output
+///     }.scope_boxed()
+/// }
+///
+/// let log = Log::default();
+/// let layers: [ScopedBoxLayer<'_, '_, u8>; 2] = [Box::new(auth(&log)), Box::new(logging(&log))];
+/// let step = async { log.lock().unwrap().push("step"); 1 };
+/// assert_eq!(1, futures::executor::block_on(step.scope_layered(layers)));
+/// assert_eq!(vec!["authorized", "started", "step", "finished"], log.into_inner().unwrap());
+/// # } #[cfg(feature = "std")] test();
+/// ```
+pub trait ScopedLayer<'upper_bound, 'subject, T> {
+    /// Wraps `next`, which the returned future is expected to await.
+    fn layer(self, next: ScopedBoxFuture<'upper_bound, 'subject, T>) -> ScopedBoxFuture<'upper_bound, 'subject, T>;
+}
+
+impl<'upper_bound: 'subject, 'subject, T, F> ScopedLayer<'upper_bound, 'subject, T> for F
+where
+    F: FnOnce(ScopedBoxFuture<'upper_bound, 'subject, T>) -> ScopedBoxFuture<'upper_bound, 'subject, T>,
+{
+    fn layer(self, next: ScopedBoxFuture<'upper_bound, 'subject, T>) -> ScopedBoxFuture<'upper_bound, 'subject, T> {
+        self(next)
+    }
+}
+
+/// A boxed [`ScopedLayer`], for combining layers of different types.
+pub type ScopedBoxLayer<'upper_bound, 'subject, T> =
+    Box<dyn FnOnce(ScopedBoxFuture<'upper_bound, 'subject, T>) -> ScopedBoxFuture<'upper_bound, 'subject, T> + Send + 'subject>;
@@ -2,10 +2,206 @@
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 #[cfg(feature = "alloc")]
-use alloc::boxed::Box;
-use core::{future::Future, marker::PhantomData, pin::Pin};
+use alloc::{boxed::Box, vec::Vec};
+use core::{cell::Cell, future::Future, marker::PhantomData, mem::MaybeUninit, pin::Pin, time::Duration};
+
+#[cfg(feature = "alloc")]
+mod arena;
+#[cfg(feature = "alloc")]
+mod barrier;
+#[cfg(feature = "alloc")]
+mod box_err;
+#[cfg(feature = "alloc")]
+mod callback;
+mod cancel_reason;
+mod checkpoint;
+#[cfg(feature = "alloc")]
+mod coalesce_wakes;
+mod collect;
+#[cfg(feature = "alloc")]
+mod completion_signal;
+#[cfg(feature = "std")]
+mod context;
+mod depth_limit;
+#[cfg(feature = "alloc")]
+mod each_step;
+mod eager;
+#[cfg(feature = "alloc")]
+mod erase_static;
+#[cfg(feature = "alloc")]
+mod external_waker;
+mod extract;
+#[cfg(feature = "alloc")]
+mod fan_out;
+mod finalized;
+#[cfg(feature = "alloc")]
+mod for_each_concurrent;
+#[cfg(feature = "alloc")]
+mod format;
+#[cfg(feature = "alloc")]
+mod from_callback;
+#[cfg(feature = "std")]
+mod get_or_insert;
+mod guard;
+mod heartbeat;
+#[cfg(feature = "std")]
+mod histogram;
+mod idempotent;
+#[cfg(feature = "io")]
+mod io;
+#[cfg(feature = "alloc")]
+mod layer;
+mod log_err;
+mod map_ref;
+mod maybe_done;
+mod non_reentrant;
+#[cfg(feature = "alloc")]
+mod nth_complete;
+#[cfg(feature = "alloc")]
+mod nursery;
+#[cfg(feature = "alloc")]
+mod output;
+mod pin_to_worker;
+mod profile;
+#[cfg(feature = "alloc")]
+mod reborrow;
+#[cfg(feature = "alloc")]
+mod reduce;
+mod release_on_drop;
+#[cfg(feature = "test-util")]
+mod replay;
+mod retry;
+mod save_point;
+mod select_recv;
+#[cfg(feature = "alloc")]
+mod self_ref;
+#[cfg(feature = "alloc")]
+mod sendness;
+mod sequence;
+#[cfg(feature = "alloc")]
+mod serialize;
+#[cfg(feature = "alloc")]
+mod single_flight;
+#[cfg(feature = "alloc")]
+mod spin_lock;
+mod split_borrow;
+mod stream;
+mod tag;
+#[cfg(feature = "alloc")]
+mod tee;
+mod time;
+mod timeout_or;
+mod timeout_partial;
+mod try_future;
+mod try_stream;
+#[cfg(feature = "std")]
+mod warn_slow;
+mod yield_now;
+
+#[cfg(feature = "alloc")]
+pub use arena::{scope_arena, ScopedArena};
+#[cfg(feature = "alloc")]
+pub use barrier::{AtBarrier, BarrierWait, ScopedBarrier};
+#[cfg(feature = "alloc")]
+pub use box_err::{BoxErr, ScopedBoxError};
+#[cfg(feature = "alloc")]
+pub use callback::{scoped_callback, ScopedBoxCallback, ScopedCallback};
+pub use cancel_reason::WithCancelReason;
+pub use checkpoint::{Checkpoint, WithCheckpoints};
+#[cfg(feature = "alloc")]
+pub use coalesce_wakes::CoalesceWakes;
+pub use collect::{Collect, CollectMap};
+#[cfg(feature = "alloc")]
+pub use completion_signal::{CompletionSignal, Signaled};
+#[cfg(feature = "std")]
+pub use context::{with_context, WithContext};
+pub use depth_limit::{scope_depth_limited, DepthExceeded, DepthLimit, DepthLimited};
+#[cfg(feature = "alloc")]
+pub use each_step::scope_each_step;
+pub use eager::Eager;
+#[cfg(feature = "alloc")]
+pub use erase_static::scope_erase_to_static;
+#[cfg(feature = "alloc")]
+pub use external_waker::{ExternallyWoken, WakerHandle};
+pub use extract::Extract;
+pub use finalized::ScopedFinalized;
+#[cfg(feature = "alloc")]
+pub use for_each_concurrent::{scope_for_each_concurrent, ForEachConcurrent};
+#[cfg(feature = "alloc")]
+pub use format::{DebugString, DisplayString};
+#[cfg(feature = "alloc")]
+pub use from_callback::{scoped_from_callback, FromCallback};
+#[cfg(feature = "std")]
+pub use get_or_insert::scope_get_or_insert_with;
+pub use guard::{GuardIntoOwned, IntoOwnedGuard};
+pub use heartbeat::WithHeartbeat;
+#[cfg(feature = "std")]
+pub use histogram::{Record, WithHistogram};
+pub use idempotent::{IdempotencyGuard, Idempotent};
+#[cfg(feature = "io")]
+pub use io::{scope_io_copy, scope_read_to_end, IoCopy, ReadToEnd};
+#[cfg(feature = "alloc")]
+pub use layer::{ScopedBoxLayer, ScopedLayer};
+pub use log_err::LogErrAndDefault;
+pub use map_ref::MapRef;
+pub use non_reentrant::NonReentrant;
+#[cfg(feature = "alloc")]
+pub use nth_complete::{scoped_nth_complete, NthComplete};
+#[cfg(feature = "alloc")]
+pub use nursery::{scope, Scope, ScopeHandle, ShutdownRequested};
+#[cfg(feature = "alloc")]
+pub use output::ScopedOutput;
+pub use pin_to_worker::PinToWorker;
+pub use profile::{PollStats, Profile};
+#[cfg(feature = "alloc")]
+pub use reborrow::{scope_reborrow_mut, Lock, ReborrowGuard, ReborrowMut};
+#[cfg(feature = "alloc")]
+pub use reduce::{scoped_reduce, Reduce};
+pub use release_on_drop::ReleaseOnDrop;
+#[cfg(feature = "test-util")]
+pub use replay::{ReplayFuture, ReplayStep};
+pub use retry::{scope_retry_jitter, JitterRng, RetryPolicy};
+pub use save_point::{Savepoint, WithSavepoint};
+pub use select_recv::{scope_select_recv, SelectRecv, SelectRecvOutput};
+#[cfg(feature = "alloc")]
+pub use self_ref::ScopedSelfRef;
+#[cfg(feature = "alloc")]
+pub use sendness::ScopedSendness;
+pub use sequence::{scope_sequence, Sequence};
+#[cfg(feature = "alloc")]
+pub use serialize::SerializeWith;
+#[cfg(feature = "alloc")]
+pub use single_flight::{LocalSingleFlight, LocalSingleFlightGroup};
+pub use split_borrow::{scope_split_borrow, SplitBorrow};
+#[cfg(feature = "alloc")]
+pub use stream::{
+    scope_bounded_collect, scope_window, scoped_merge_priority, scoped_partition, BoundedCollect, LimitExceeded, MergePriority, PartitionErrs, PartitionOks,
+    Window,
+};
+#[cfg(feature = "std")]
+pub use stream::{scoped_catch_unwind_stream, CatchUnwindStream, Panic};
+pub use stream::{
+    scoped_dedup, scoped_stream_from_iter, scoped_stream_timeout, scoped_throttle, Dedup, Flatten, FromIter, ScopedStream, ScopedStreamExt,
+    ScopedStreamWrapper, StreamTimeout, Throttle, ThrottleMode,
+};
+#[cfg(feature = "alloc")]
+pub use stream::{ScopedBoxStream, ScopedBoxStreamDowncast, ScopedBoxStreamStream, ScopedLocalBoxStream};
+pub use tag::Tagged;
+#[cfg(feature = "alloc")]
+pub use tee::{TeeStream, TeeSummary};
+pub use time::Timer;
+pub use timeout_or::TimeoutOr;
+pub use timeout_partial::{Progress, TimeoutPartial};
+pub use try_future::{ContextError, ErrorContext, TryScopedFuture, TryScopedFutureExt};
+pub use try_stream::IntoTryStream;
+#[cfg(feature = "std")]
+pub use warn_slow::WarnSlow;
+#[doc(hidden)]
+pub use yield_now::yield_now;
 
 /// A [`Future`] super-trait with an implied upper bound on the provided lifetime.
 /// This is especially useful for callbacks that use higher-ranked lifetimes in their return type,
@@ -121,6 +317,225 @@ pub trait ScopedFutureExt: Sized {
     fn scope_boxed_local<'upper_bound, 'subject>(self) -> ScopedLocalBoxFuture<'upper_bound, 'subject, <Self as Future>::Output>
     where
         Self: Future + 'subject;
+
+    /// Records the number of polls and the time from first poll to completion of this [`Future`] into `histogram`.
+    #[cfg(feature = "std")]
+    fn scope_histogram<H: Record>(self, histogram: H) -> WithHistogram<Self, H>;
+
+    /// Maps the borrowed output of this [`Future`] into an owned value while the borrow is still valid.
+    fn scope_map_ref<'subject, T, U, F>(self, f: F) -> MapRef<Self, F>
+    where
+        Self: Future<Output = &'subject T>,
+        T: ?Sized + 'subject,
+        F: FnOnce(&T) -> U;
+
+    /// Asserts that this [`Future`] completes on its first poll, panicking in debug builds otherwise.
+    #[track_caller]
+    fn scope_eager(self) -> Eager<Self>
+    where
+        Self: Future;
+
+    /// Completes with the output of this [`Future`], or with its partial progress if `duration` elapses first.
+    fn scope_timeout_partial<T: Timer>(self, timer: T, duration: Duration) -> TimeoutPartial<Self, T::Sleep>
+    where
+        Self: Progress;
+
+    /// Calls `on_checkpoint` whenever this [`Future`] yields after reaching a checkpoint.
+    fn scope_checkpoint<F>(self, on_checkpoint: F) -> WithCheckpoints<Self, F>
+    where
+        Self: Checkpoint,
+        F: FnMut(<Self as Checkpoint>::Marker);
+
+    /// Runs the future returned by `beat_fn` every `interval` while this [`Future`] is pending.
+    /// The heartbeat stops once this future completes.
+    fn scope_with_heartbeat<T, F, Beat>(self, timer: T, interval: Duration, beat_fn: F) -> WithHeartbeat<Self, T, F, Beat>
+    where
+        Self: Future,
+        T: Timer,
+        F: FnMut() -> Beat,
+        Beat: Future<Output = ()>;
+
+    /// Collects the iterator output of this [`Future`] into `C` before the borrow it was produced from ends.
+    fn scope_collect<C>(self) -> Collect<Self, C>
+    where
+        Self: Future<Output: IntoIterator>,
+        C: FromIterator<<<Self as Future>::Output as IntoIterator>::Item>;
+
+    /// Maps each item of the iterator output of this [`Future`] using `f` and collects them into `C`
+    /// before the borrow the iterator was produced from ends.
+    fn scope_collect_map<C, U, F>(self, f: F) -> CollectMap<Self, F, C>
+    where
+        Self: Future<Output: IntoIterator>,
+        F: FnMut(<<Self as Future>::Output as IntoIterator>::Item) -> U,
+        C: FromIterator<U>;
+
+    /// Calls `on_cancel` with the reason for cancellation if this [`Future`] is dropped before completing.
+    /// The reason can be set with [`WithCancelReason::set_cancel_reason`] and otherwise defaults to [`Default::default`].
+    fn scope_with_cancel_reason<F, R>(self, on_cancel: F) -> WithCancelReason<Self, F, R>
+    where
+        F: FnOnce(R),
+        R: Default;
+
+    /// Returns this [`Future`] along with a handle which external code can use to force it to be polled again.
+    #[cfg(feature = "alloc")]
+    fn scope_with_external_waker<'subject>(self) -> (ExternallyWoken<'subject, Self>, WakerHandle<'subject>)
+    where
+        Self: Future + 'subject;
+
+    /// Calls `warn` with the duration of each poll of this [`Future`] which takes longer than `threshold`,
+    /// catching futures which block their executor without reporting every poll.
+    #[cfg(feature = "std")]
+    fn scope_warn_slow<F>(self, threshold: Duration, warn: F) -> WarnSlow<Self, F>
+    where
+        Self: Future,
+        F: FnMut(Duration);
+
+    /// Converts the borrowed guard output of this [`Future`] into its owned equivalent.
+    fn scope_guard_into_owned(self) -> GuardIntoOwned<Self>
+    where
+        Self: Future<Output: IntoOwnedGuard>;
+
+    /// Moves this [`Future`] into a caller-provided slot and pins it there, as an alternative to boxing
+    /// for executors which manage their own future storage.
+    ///
+    /// # Safety
+    /// Pinned futures must be dropped before their memory is reused or invalidated, but [`MaybeUninit`]
+    /// never drops its contents. The caller must drop the returned future in place, e.g. with
+    /// [`core::ptr::drop_in_place`], before `slot` is written to again, deallocated or goes out of scope.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use core::{future::Future, mem::MaybeUninit, pin::pin, task::{Context, Poll, Waker}};
+    /// use scoped_futures::ScopedFutureExt;
+    ///
+    /// let rows = [1, 2, 3];
+    /// let mut slot = pin!(MaybeUninit::uninit());
+    /// // SAFETY: the future is dropped in place below, before the slot goes out of scope
+    /// let mut future = unsafe { async { rows.iter().sum::<u8>() }.scope_pin_into(slot.as_mut()) };
+    /// assert_eq!(Poll::Ready(6), future.as_mut().poll(&mut Context::from_waker(Waker::noop())));
+    /// // SAFETY: the future is not used again after being dropped
+    /// unsafe { core::ptr::drop_in_place(future.get_unchecked_mut()) };
+    /// # } test();
+    /// ```
+    unsafe fn scope_pin_into(self, slot: Pin<&mut MaybeUninit<Self>>) -> Pin<&mut Self>
+    where
+        Self: Future;
+
+    /// Bundles `id` with the output of this [`Future`], for correlating the outputs of many futures driven together.
+    fn scope_tag<Id>(self, id: Id) -> Tagged<Self, Id>
+    where
+        Self: Future;
+
+    /// Awaits `finalizer` after this [`Future`] completes or is [cancelled](ScopedFinalized::cancel).
+    fn scope_finalized<Fin>(self, finalizer: Fin) -> ScopedFinalized<Self, Fin>
+    where
+        Self: Future,
+        Fin: Future<Output = ()>;
+
+    /// Waits at `barrier` before polling this [`Future`], so that several futures within a scope start together.
+    #[cfg(feature = "alloc")]
+    fn scope_at_barrier(self, barrier: &ScopedBarrier) -> AtBarrier<'_, Self>
+    where
+        Self: Future;
+
+    /// Splits the collection produced by this [`Future`] into a stream of its items and a future of the
+    /// number of items which that stream yields, both driven by polling the stream.
+    #[cfg(feature = "alloc")]
+    fn scope_tee_stream(self) -> (TeeStream<Self, <<Self as Future>::Output as IntoIterator>::IntoIter>, TeeSummary)
+    where
+        Self: Future,
+        <Self as Future>::Output: IntoIterator;
+
+    /// Panics in debug builds if this [`Future`] is polled while a poll sharing `flag` is already in progress.
+    #[track_caller]
+    fn scope_non_reentrant(self, flag: &Cell<bool>) -> NonReentrant<'_, Self>
+    where
+        Self: Future;
+
+    /// Completes with the output of this [`Future`], or with `default` if `duration` elapses first.
+    fn scope_timeout_or<T: Timer>(self, timer: T, duration: Duration, default: <Self as Future>::Output) -> TimeoutOr<Self, T::Sleep>
+    where
+        Self: Future;
+
+    /// Makes `context` available through [`with_context`] on this thread for the duration of each poll of this [`Future`].
+    /// Contexts are looked up by type, so `C` has to be `'static` even though `context` itself may be a short-lived borrow.
+    #[cfg(feature = "std")]
+    fn scope_with_context<C: 'static>(self, context: &C) -> WithContext<'_, Self, C>
+    where
+        Self: Future;
+
+    /// Pairs this [`Future`] with a signal which resolves once it completes, without consuming its output.
+    #[cfg(feature = "alloc")]
+    fn scope_completion_signal(self) -> (Signaled<Self>, CompletionSignal)
+    where
+        Self: Future;
+
+    /// Completes with the output of this [`Future`] alongside statistics about how it was polled.
+    fn scope_profile(self) -> Profile<Self>
+    where
+        Self: Future;
+
+    /// Calls `release` exactly once, right after this [`Future`] is dropped, whether it completed or was cancelled.
+    fn scope_release_on_drop<F: FnOnce()>(self, release: F) -> ReleaseOnDrop<Self, F>
+    where
+        Self: Future;
+
+    /// Wraps this [`Future`] in each of `layers`, with the first layer being the outermost one.
+    #[cfg(feature = "alloc")]
+    fn scope_layered<'upper_bound, 'subject, L>(self, layers: L) -> ScopedBoxFuture<'upper_bound, 'subject, <Self as Future>::Output>
+    where
+        Self: Future + Send + 'subject,
+        L: IntoIterator<Item: ScopedLayer<'upper_bound, 'subject, <Self as Future>::Output>, IntoIter: DoubleEndedIterator>;
+
+    /// Formats the output of this [`Future`] into a [`String`](alloc::string::String) with its [`Display`](core::fmt::Display) implementation.
+    #[cfg(feature = "alloc")]
+    fn scope_display(self) -> DisplayString<Self>
+    where
+        Self: Future<Output: core::fmt::Display>;
+
+    /// Formats the output of this [`Future`] into a [`String`](alloc::string::String) with its [`Debug`](core::fmt::Debug) implementation.
+    #[cfg(feature = "alloc")]
+    fn scope_debug(self) -> DebugString<Self>
+    where
+        Self: Future<Output: core::fmt::Debug>;
+
+    /// Coalesces repeated wakes of this [`Future`] into a single wake, and skips polling it again until it has been woken.
+    #[cfg(feature = "alloc")]
+    fn scope_coalesce_wakes(self) -> CoalesceWakes<Self>
+    where
+        Self: Future;
+
+    /// Hints that this [`Future`] should run on the executor worker `worker_id` for cache locality.
+    /// Where the executor does not support pinning futures to workers, this is a passthrough.
+    fn scope_pin_to_worker(self, worker_id: usize) -> PinToWorker<Self>
+    where
+        Self: Future;
+
+    /// Allows the state reached by this [`Future`] to be extracted if it is cancelled before completing.
+    fn scope_save_point(self) -> WithSavepoint<Self>
+    where
+        Self: Savepoint;
+
+    /// Extracts an owned value from the output of this [`Future`] with `f`, dropping the borrowing output afterwards.
+    fn scope_extract<U, F>(self, f: F) -> Extract<Self, F>
+    where
+        Self: Future,
+        F: FnOnce(&<Self as Future>::Output) -> U;
+
+    /// Skips this [`Future`], resolving to [`None`], if `guard` records that its side effect was already applied by a
+    /// previous attempt, and otherwise records the side effect as applied once this [`Future`] completes.
+    fn scope_idempotent<G: IdempotencyGuard>(self, guard: G) -> Idempotent<Self, G>
+    where
+        Self: Future;
+
+    /// Serializes the output of this [`Future`] into bytes with `serialize`, e.g. `serde_json::to_writer`, before
+    /// dropping the borrowing output.
+    #[cfg(feature = "alloc")]
+    fn scope_serialize_with<E, F>(self, serialize: F) -> SerializeWith<Self, F>
+    where
+        Self: Future,
+        F: FnOnce(&<Self as Future>::Output, &mut Vec<u8>) -> Result<(), E>;
 }
 
 impl<'upper_bound, 'subject, Fut: Future> Future for ScopedFutureWrapper<'upper_bound, 'subject, Fut> {
@@ -150,6 +565,258 @@ impl<Fut: Future> ScopedFutureExt for Fut {
     {
         Box::pin(self)
     }
+
+    #[cfg(feature = "std")]
+    fn scope_histogram<H: Record>(self, histogram: H) -> WithHistogram<Self, H> {
+        WithHistogram::new(self, histogram)
+    }
+
+    fn scope_map_ref<'subject, T, U, F>(self, f: F) -> MapRef<Self, F>
+    where
+        Self: Future<Output = &'subject T>,
+        T: ?Sized + 'subject,
+        F: FnOnce(&T) -> U,
+    {
+        MapRef::new(self, f)
+    }
+
+    #[track_caller]
+    fn scope_eager(self) -> Eager<Self>
+    where
+        Self: Future,
+    {
+        Eager::new(self)
+    }
+
+    fn scope_timeout_partial<T: Timer>(self, timer: T, duration: Duration) -> TimeoutPartial<Self, T::Sleep>
+    where
+        Self: Progress,
+    {
+        TimeoutPartial::new(self, timer.sleep(duration))
+    }
+
+    fn scope_checkpoint<F>(self, on_checkpoint: F) -> WithCheckpoints<Self, F>
+    where
+        Self: Checkpoint,
+        F: FnMut(<Self as Checkpoint>::Marker),
+    {
+        WithCheckpoints::new(self, on_checkpoint)
+    }
+
+    fn scope_with_heartbeat<T, F, Beat>(self, timer: T, interval: Duration, beat_fn: F) -> WithHeartbeat<Self, T, F, Beat>
+    where
+        Self: Future,
+        T: Timer,
+        F: FnMut() -> Beat,
+        Beat: Future<Output = ()>,
+    {
+        WithHeartbeat::new(self, timer, interval, beat_fn)
+    }
+
+    fn scope_collect<C>(self) -> Collect<Self, C>
+    where
+        Self: Future<Output: IntoIterator>,
+        C: FromIterator<<<Self as Future>::Output as IntoIterator>::Item>,
+    {
+        Collect::new(self)
+    }
+
+    fn scope_collect_map<C, U, F>(self, f: F) -> CollectMap<Self, F, C>
+    where
+        Self: Future<Output: IntoIterator>,
+        F: FnMut(<<Self as Future>::Output as IntoIterator>::Item) -> U,
+        C: FromIterator<U>,
+    {
+        CollectMap::new(self, f)
+    }
+
+    fn scope_with_cancel_reason<F, R>(self, on_cancel: F) -> WithCancelReason<Self, F, R>
+    where
+        F: FnOnce(R),
+        R: Default,
+    {
+        WithCancelReason::new(self, on_cancel)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn scope_with_external_waker<'subject>(self) -> (ExternallyWoken<'subject, Self>, WakerHandle<'subject>)
+    where
+        Self: Future + 'subject,
+    {
+        ExternallyWoken::new(self)
+    }
+
+    #[cfg(feature = "std")]
+    fn scope_warn_slow<F>(self, threshold: Duration, warn: F) -> WarnSlow<Self, F>
+    where
+        Self: Future,
+        F: FnMut(Duration),
+    {
+        WarnSlow::new(self, threshold, warn)
+    }
+
+    fn scope_guard_into_owned(self) -> GuardIntoOwned<Self>
+    where
+        Self: Future<Output: IntoOwnedGuard>,
+    {
+        GuardIntoOwned::new(self)
+    }
+
+    unsafe fn scope_pin_into(self, slot: Pin<&mut MaybeUninit<Self>>) -> Pin<&mut Self>
+    where
+        Self: Future,
+    {
+        // SAFETY: the slot is never moved out of, and the caller guarantees the future is dropped in place
+        // before the slot's memory is reused or invalidated
+        unsafe { Pin::new_unchecked(slot.get_unchecked_mut().write(self)) }
+    }
+
+    fn scope_tag<Id>(self, id: Id) -> Tagged<Self, Id>
+    where
+        Self: Future,
+    {
+        Tagged::new(self, id)
+    }
+
+    fn scope_finalized<Fin>(self, finalizer: Fin) -> ScopedFinalized<Self, Fin>
+    where
+        Self: Future,
+        Fin: Future<Output = ()>,
+    {
+        ScopedFinalized::new(self, finalizer)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn scope_at_barrier(self, barrier: &ScopedBarrier) -> AtBarrier<'_, Self>
+    where
+        Self: Future,
+    {
+        AtBarrier::new(self, barrier)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn scope_tee_stream(self) -> (TeeStream<Self, <<Self as Future>::Output as IntoIterator>::IntoIter>, TeeSummary)
+    where
+        <Self as Future>::Output: IntoIterator,
+    {
+        TeeStream::new(self)
+    }
+
+    #[track_caller]
+    fn scope_non_reentrant(self, flag: &Cell<bool>) -> NonReentrant<'_, Self>
+    where
+        Self: Future,
+    {
+        NonReentrant::new(self, flag)
+    }
+
+    fn scope_timeout_or<T: Timer>(self, timer: T, duration: Duration, default: <Self as Future>::Output) -> TimeoutOr<Self, T::Sleep>
+    where
+        Self: Future,
+    {
+        TimeoutOr::new(self, timer.sleep(duration), default)
+    }
+
+    #[cfg(feature = "std")]
+    fn scope_with_context<C: 'static>(self, context: &C) -> WithContext<'_, Self, C>
+    where
+        Self: Future,
+    {
+        WithContext::new(self, context)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn scope_completion_signal(self) -> (Signaled<Self>, CompletionSignal)
+    where
+        Self: Future,
+    {
+        Signaled::new(self)
+    }
+
+    fn scope_profile(self) -> Profile<Self>
+    where
+        Self: Future,
+    {
+        Profile::new(self)
+    }
+
+    fn scope_release_on_drop<F: FnOnce()>(self, release: F) -> ReleaseOnDrop<Self, F>
+    where
+        Self: Future,
+    {
+        ReleaseOnDrop::new(self, release)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn scope_layered<'upper_bound, 'subject, L>(self, layers: L) -> ScopedBoxFuture<'upper_bound, 'subject, <Self as Future>::Output>
+    where
+        Self: Future + Send + 'subject,
+        L: IntoIterator<Item: ScopedLayer<'upper_bound, 'subject, <Self as Future>::Output>, IntoIter: DoubleEndedIterator>,
+    {
+        layers.into_iter().rev().fold(self.scope_boxed(), |next, layer| layer.layer(next))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn scope_display(self) -> DisplayString<Self>
+    where
+        Self: Future<Output: core::fmt::Display>,
+    {
+        DisplayString::new(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn scope_debug(self) -> DebugString<Self>
+    where
+        Self: Future<Output: core::fmt::Debug>,
+    {
+        DebugString::new(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn scope_coalesce_wakes(self) -> CoalesceWakes<Self>
+    where
+        Self: Future,
+    {
+        CoalesceWakes::new(self)
+    }
+
+    fn scope_pin_to_worker(self, worker_id: usize) -> PinToWorker<Self>
+    where
+        Self: Future,
+    {
+        PinToWorker::new(self, worker_id)
+    }
+
+    fn scope_save_point(self) -> WithSavepoint<Self>
+    where
+        Self: Savepoint,
+    {
+        WithSavepoint::new(self)
+    }
+
+    fn scope_extract<U, F>(self, f: F) -> Extract<Self, F>
+    where
+        Self: Future,
+        F: FnOnce(&<Self as Future>::Output) -> U,
+    {
+        Extract::new(self, f)
+    }
+
+    fn scope_idempotent<G: IdempotencyGuard>(self, guard: G) -> Idempotent<Self, G>
+    where
+        Self: Future,
+    {
+        Idempotent::new(self, guard)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn scope_serialize_with<E, F>(self, serialize: F) -> SerializeWith<Self, F>
+    where
+        Self: Future,
+        F: FnOnce(&<Self as Future>::Output, &mut Vec<u8>) -> Result<(), E>,
+    {
+        SerializeWith::new(self, serialize)
+    }
 }
 
 #[cfg(feature = "alloc")]
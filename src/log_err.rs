@@ -0,0 +1,58 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_core::TryFuture;
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_log_err_and_default`](crate::TryScopedFutureExt::scope_log_err_and_default) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use scoped_futures::TryScopedFutureExt;
+    ///
+    /// async fn touch(rows: &mut Vec<u8>, is_ok: bool) -> Result<usize, &'static str> {
+    ///     if !is_ok {
+    ///         return Err("lock timeout");
+    ///     }
+    ///     rows.push(0);
+    ///     Ok(rows.len())
+    /// }
+    ///
+    /// let mut rows = vec![];
+    /// let mut logged = vec![];
+    /// futures::executor::block_on(async {
+    ///     assert_eq!(1, touch(&mut rows, true).scope_log_err_and_default(0, |error| logged.push(error)).await);
+    ///     assert_eq!(0, touch(&mut rows, false).scope_log_err_and_default(0, |error| logged.push(error)).await);
+    /// });
+    /// assert_eq!(vec!["lock timeout"], logged);
+    /// # } test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct LogErrAndDefault<Fut: TryFuture, F> {
+        #[pin]
+        future: Fut,
+        fallback: Option<(Fut::Ok, F)>,
+    }
+}
+
+impl<Fut: TryFuture, F> LogErrAndDefault<Fut, F> {
+    pub(crate) fn new(future: Fut, default: Fut::Ok, log: F) -> Self {
+        Self { future, fallback: Some((default, log)) }
+    }
+}
+
+impl<Fut: TryFuture, F: FnOnce(Fut::Error)> Future for LogErrAndDefault<Fut, F> {
+    type Output = Fut::Ok;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let output = core::task::ready!(this.future.try_poll(cx));
+        let (default, log) = this.fallback.take().expect("LogErrAndDefault polled after completion");
+        Poll::Ready(output.unwrap_or_else(|error| {
+            log(error);
+            default
+        }))
+    }
+}
@@ -0,0 +1,48 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_map_ref`](crate::ScopedFutureExt::scope_map_ref) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use scoped_futures::ScopedFutureExt;
+    ///
+    /// async fn first_word(text: &str) -> &str {
+    ///     text.split(' ').next().unwrap_or_default()
+    /// }
+    ///
+    /// let len = futures::executor::block_on(async {
+    ///     let text = String::from("hello world");
+    ///     first_word(&text).scope_map_ref(str::len).await
+    /// });
+    /// assert_eq!(5, len);
+    /// # } test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct MapRef<Fut, F> {
+        #[pin]
+        future: Fut,
+        f: Option<F>,
+    }
+}
+
+impl<Fut, F> MapRef<Fut, F> {
+    pub(crate) fn new(future: Fut, f: F) -> Self {
+        Self { future, f: Some(f) }
+    }
+}
+
+impl<'subject, T: ?Sized + 'subject, U, Fut: Future<Output = &'subject T>, F: FnOnce(&T) -> U> Future for MapRef<Fut, F> {
+    type Output = U;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let output = core::task::ready!(this.future.poll(cx));
+        let f = this.f.take().expect("MapRef polled after completion");
+        Poll::Ready(f(output))
+    }
+}
@@ -0,0 +1,44 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project_lite::pin_project! {
+    /// A future which holds onto its output once complete until it is taken.
+    #[project = MaybeDoneProj]
+    #[project_replace = MaybeDoneProjReplace]
+    pub(crate) enum MaybeDone<Fut: Future> {
+        Future { #[pin] future: Fut },
+        Done { output: Fut::Output },
+        Gone,
+    }
+}
+
+impl<Fut: Future> MaybeDone<Fut> {
+    pub(crate) fn new(future: Fut) -> Self {
+        Self::Future { future }
+    }
+
+    /// Polls the inner future if it has not yet completed, returning whether an output is available.
+    pub(crate) fn poll_done(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> bool {
+        match self.as_mut().project() {
+            MaybeDoneProj::Future { future } => match future.poll(cx) {
+                Poll::Ready(output) => {
+                    self.project_replace(Self::Done { output });
+                    true
+                }
+                Poll::Pending => false,
+            },
+            MaybeDoneProj::Done { .. } => true,
+            MaybeDoneProj::Gone => false,
+        }
+    }
+
+    pub(crate) fn take_output(self: Pin<&mut Self>) -> Option<Fut::Output> {
+        match self.project_replace(Self::Gone) {
+            MaybeDoneProjReplace::Done { output } => Some(output),
+            _ => None,
+        }
+    }
+}
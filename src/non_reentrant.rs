@@ -0,0 +1,82 @@
+use core::{
+    cell::Cell,
+    future::Future,
+    panic::Location,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_non_reentrant`](crate::ScopedFutureExt::scope_non_reentrant) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use core::cell::Cell;
+    /// use scoped_futures::ScopedFutureExt;
+    ///
+    /// let flag = Cell::new(false);
+    /// assert_eq!(1, futures::executor::block_on(async { 1 }.scope_non_reentrant(&flag)));
+    /// assert_eq!(2, futures::executor::block_on(async { 2 }.scope_non_reentrant(&flag)));
+    /// # } test();
+    /// ```
+    ///
+    /// Polling while another poll sharing the same flag is in progress panics when this crate is built with debug assertions.
+    /// The panic message reports where the re-entrantly polled future was constructed.
+    /// The check is skipped otherwise, so re-entrant polls go through.
+    /// ```
+    /// # fn test() {
+    /// use core::{cell::Cell, future::Future, panic::{AssertUnwindSafe, Location}, pin::pin};
+    /// use scoped_futures::ScopedFutureExt;
+    ///
+    /// let flag = &Cell::new(false);
+    /// let (inner, constructed) = (async { 1 }.scope_non_reentrant(flag), Location::caller());
+    /// let output = std::panic::catch_unwind(AssertUnwindSafe(move || {
+    ///     let mut inner = pin!(inner);
+    ///     let outer = futures::future::poll_fn(|cx| inner.as_mut().poll(cx)).scope_non_reentrant(flag);
+    ///     futures::executor::block_on(outer)
+    /// }));
+    /// match output {
+    ///     Ok(output) => assert_eq!(1, output),
+    ///     Err(panic) => assert!(panic.downcast::<String>().unwrap().contains(&format!("{}:{}:", constructed.file(), constructed.line()))),
+    /// }
+    /// # } test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct NonReentrant<'flag, Fut> {
+        #[pin]
+        future: Fut,
+        flag: &'flag Cell<bool>,
+        location: &'static Location<'static>,
+    }
+}
+
+impl<'flag, Fut> NonReentrant<'flag, Fut> {
+    #[track_caller]
+    pub(crate) fn new(future: Fut, flag: &'flag Cell<bool>) -> Self {
+        Self { future, flag, location: Location::caller() }
+    }
+}
+
+struct Reset<'flag>(&'flag Cell<bool>);
+
+impl Drop for Reset<'_> {
+    fn drop(&mut self) {
+        self.0.set(false);
+    }
+}
+
+impl<Fut: Future> Future for NonReentrant<'_, Fut> {
+    type Output = Fut::Output;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        if cfg!(debug_assertions) {
+            if this.flag.replace(true) {
+                panic!("non-reentrant future constructed at {} was polled re-entrantly", this.location);
+            }
+            let _reset = Reset(this.flag);
+            return this.future.poll(cx);
+        }
+        this.future.poll(cx)
+    }
+}
@@ -0,0 +1,76 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Future for the [`scoped_nth_complete`] function.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct NthComplete<Fut> {
+    pending: Vec<Pin<Box<Fut>>>,
+    remaining: usize,
+}
+
+/// Concurrently awaits the provided futures until `n` of them have completed, resolving to the output of the
+/// `n`th one along with the futures which are still pending. The outputs of the earlier completions are dropped.
+///
+/// This generalizes `futures::future::select_all`, which corresponds to an `n` of 1.
+///
+/// # Panics
+/// Panics if `n` is zero or greater than the number of provided futures.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use scoped_futures::scoped_nth_complete;
+/// # use scoped_futures::yield_now;
+///
+/// async fn replica(name: &str, delay: usize) -> &str {
+///     for _ in 0..delay {
+///         yield_now().await;
+///     }
+///     name
+/// }
+///
+/// let replicas = vec![("a", 3), ("b", 1), ("c", 2), ("d", 0)];
+/// futures::executor::block_on(async {
+///     let quorum = scoped_nth_complete(replicas.iter().map(|(name, delay)| replica(name, *delay)), 2);
+///     let (name, remaining) = quorum.await;
+///     assert_eq!("b", name);
+///     assert_eq!(vec!["a", "c"], futures::future::join_all(remaining).await);
+/// });
+/// # } #[cfg(feature = "alloc")] test();
+/// ```
+pub fn scoped_nth_complete<I>(futures: I, n: usize) -> NthComplete<I::Item>
+where
+    I: IntoIterator<Item: Future>,
+{
+    let pending: Vec<_> = futures.into_iter().map(Box::pin).collect();
+    assert!(n > 0 && n <= pending.len(), "scoped_nth_complete called with n = {n} for {} futures", pending.len());
+    NthComplete { pending, remaining: n }
+}
+
+impl<Fut> Unpin for NthComplete<Fut> {}
+
+impl<Fut: Future> Future for NthComplete<Fut> {
+    type Output = (Fut::Output, Vec<Pin<Box<Fut>>>);
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        assert!(this.remaining > 0, "NthComplete polled after completion");
+        let mut i = 0;
+        while i < this.pending.len() {
+            match this.pending[i].as_mut().poll(cx) {
+                Poll::Ready(output) => {
+                    drop(this.pending.remove(i));
+                    this.remaining -= 1;
+                    if this.remaining == 0 {
+                        return Poll::Ready((output, core::mem::take(&mut this.pending)));
+                    }
+                }
+                Poll::Pending => i += 1,
+            }
+        }
+        Poll::Pending
+    }
+}
@@ -0,0 +1,166 @@
+use alloc::{boxed::Box, rc::Rc, vec::Vec};
+use core::{
+    cell::{Cell, RefCell},
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+use crate::ScopedLocalBoxFuture;
+
+struct Inner<'subject> {
+    spawned: RefCell<Vec<ScopedLocalBoxFuture<'subject, 'subject, ()>>>,
+    scope_waker: RefCell<Option<Waker>>,
+    shutdown: Cell<bool>,
+    shutdown_wakers: RefCell<Vec<Waker>>,
+}
+
+/// A handle to a [`scope`], passed into its body and cloned into its tasks, for spawning further tasks which may
+/// borrow for `'subject` and for requesting that the tasks of the scope shut down.
+///
+/// This is local to the task which polls the scope and is not [`Send`].
+#[derive(Clone)]
+pub struct ScopeHandle<'subject> {
+    inner: Rc<Inner<'subject>>,
+}
+
+impl<'subject> ScopeHandle<'subject> {
+    /// Spawns `future` onto the scope, which will not complete until `future` has completed.
+    pub fn spawn(&self, future: impl Future<Output = ()> + 'subject) {
+        self.inner.spawned.borrow_mut().push(Box::pin(future));
+        if let Some(waker) = self.inner.scope_waker.borrow_mut().take() {
+            waker.wake();
+        }
+    }
+
+    /// Requests that the tasks of the scope shut down. Tasks observe this cooperatively through
+    /// [`is_shutdown`](ScopeHandle::is_shutdown) or [`shutdown_requested`](ScopeHandle::shutdown_requested).
+    pub fn shutdown(&self) {
+        self.inner.shutdown.set(true);
+        self.inner.shutdown_wakers.take().into_iter().for_each(Waker::wake);
+    }
+
+    /// Returns whether a shutdown of the scope has been requested.
+    pub fn is_shutdown(&self) -> bool {
+        self.inner.shutdown.get()
+    }
+
+    /// Returns a future which resolves once a shutdown of the scope has been requested.
+    pub fn shutdown_requested(&self) -> ShutdownRequested<'_, 'subject> {
+        ShutdownRequested { handle: self }
+    }
+}
+
+/// Future for the [`ScopeHandle::shutdown_requested`] method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ShutdownRequested<'a, 'subject> {
+    handle: &'a ScopeHandle<'subject>,
+}
+
+impl Future for ShutdownRequested<'_, '_> {
+    type Output = ();
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let inner = &self.handle.inner;
+        if inner.shutdown.get() {
+            return Poll::Ready(());
+        }
+        let mut wakers = inner.shutdown_wakers.borrow_mut();
+        if !wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+            wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope`] function.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Scope<'subject, Fut: Future> {
+        #[pin]
+        body: Option<Fut>,
+        output: Option<Fut::Output>,
+        running: Vec<ScopedLocalBoxFuture<'subject, 'subject, ()>>,
+        handle: ScopeHandle<'subject>,
+    }
+
+    impl<'subject, Fut: Future> PinnedDrop for Scope<'subject, Fut> {
+        fn drop(this: Pin<&mut Self>) {
+            // spawned tasks may hold clones of the handle, so drop them to release the handle's state
+            this.project().handle.inner.spawned.take();
+        }
+    }
+}
+
+/// Runs the future returned by `f` as the body of a scope, whose [`ScopeHandle`] lets the body and the tasks it
+/// spawns spawn further borrowing tasks. The scope resolves to the output of its body once the body and every
+/// spawned task have completed, and dropping it cancels all of them.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use core::cell::RefCell;
+/// use scoped_futures::scope;
+/// # use scoped_futures::yield_now;
+///
+/// let log = RefCell::new(vec![]);
+/// let output = futures::executor::block_on(scope(|handle| {
+///     let log = &log;
+///     async move {
+///         let sibling = handle.clone();
+///         handle.spawn(async move {
+///             sibling.shutdown_requested().await;
+///             log.borrow_mut().push("listener stopped");
+///         });
+///         let supervisor = handle.clone();
+///         handle.spawn(async move {
+///             yield_now().await;
+///             log.borrow_mut().push("shutting down");
+///             supervisor.shutdown();
+///         });
+///         "body done"
+///     }
+/// }));
+/// assert_eq!("body done", output);
+/// assert_eq!(vec!["shutting down", "listener stopped"], log.into_inner());
+/// # } #[cfg(feature = "alloc")] test();
+/// ```
+pub fn scope<'subject, F, Fut>(f: F) -> Scope<'subject, Fut>
+where
+    F: FnOnce(ScopeHandle<'subject>) -> Fut,
+    Fut: Future,
+{
+    let handle = ScopeHandle {
+        inner: Rc::new(Inner {
+            spawned: RefCell::new(Vec::new()),
+            scope_waker: RefCell::new(None),
+            shutdown: Cell::new(false),
+            shutdown_wakers: RefCell::new(Vec::new()),
+        }),
+    };
+    Scope { body: Some(f(handle.clone())), output: None, running: Vec::new(), handle }
+}
+
+impl<Fut: Future> Future for Scope<'_, Fut> {
+    type Output = Fut::Output;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        *this.handle.inner.scope_waker.borrow_mut() = Some(cx.waker().clone());
+        if let Some(body) = this.body.as_mut().as_pin_mut() {
+            if let Poll::Ready(output) = body.poll(cx) {
+                this.body.set(None);
+                *this.output = Some(output);
+            }
+        }
+        loop {
+            this.running.extend(this.handle.inner.spawned.take());
+            this.running.retain_mut(|task| task.as_mut().poll(cx).is_pending());
+            if this.handle.inner.spawned.borrow().is_empty() {
+                break;
+            }
+        }
+        if this.body.is_some() || !this.running.is_empty() {
+            return Poll::Pending;
+        }
+        Poll::Ready(this.output.take().expect("Scope polled after completion"))
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{ScopedBoxFuture, ScopedBoxStream};
+
+/// A scoped return type which is either a single boxed future or a boxed stream, both under the same borrow scope.
+/// This allows a callback to decide at runtime whether it produces one result or a stream of results.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use futures::StreamExt;
+/// use scoped_futures::{ScopedFutureExt, ScopedOutput, ScopedStreamExt};
+///
+/// pub struct Db {
+///     rows: Vec<u8>,
+/// }
+///
+/// impl Db {
+///     async fn query<'a, F>(&mut self, callback: F) -> Vec<u8>
+///     where
+///         F: for<'b> FnOnce(&'b mut Self) -> ScopedOutput<'a, 'b, u8> + 'a,
+///     {
+///         match callback(self) {
+///             ScopedOutput::Future(future) => vec![future.await],
+///             ScopedOutput::Stream(stream) => stream.collect().await,
+///         }
+///     }
+/// }
+///
+/// futures::executor::block_on(async {
+///     let mut db = Db { rows: vec![1, 2, 3] };
+///     let streaming = false;
+///     let result = db.query(|db| match streaming {
+///         true => ScopedOutput::Stream(futures::stream::iter(db.rows.iter().copied()).scope_boxed()),
+///         false => ScopedOutput::Future(async move { db.rows.iter().sum() }.scope_boxed()),
+///     }).await;
+///     assert_eq!(vec![6], result);
+///
+///     let streaming = true;
+///     let result = db.query(|db| match streaming {
+///         true => ScopedOutput::Stream(futures::stream::iter(db.rows.iter().copied()).scope_boxed()),
+///         false => ScopedOutput::Future(async move { db.rows.iter().sum() }.scope_boxed()),
+///     }).await;
+///     assert_eq!(vec![1, 2, 3], result);
+/// });
+/// # } #[cfg(feature = "alloc")] test();
+/// ```
+pub enum ScopedOutput<'upper_bound, 'subject, T, Item = T> {
+    /// A single result.
+    Future(ScopedBoxFuture<'upper_bound, 'subject, T>),
+    /// A stream of results.
+    Stream(ScopedBoxStream<'upper_bound, 'subject, Item>),
+}
+
+impl<'upper_bound, 'subject, T, Item> From<ScopedBoxFuture<'upper_bound, 'subject, T>> for ScopedOutput<'upper_bound, 'subject, T, Item> {
+    fn from(future: ScopedBoxFuture<'upper_bound, 'subject, T>) -> Self {
+        Self::Future(future)
+    }
+}
+
+impl<'upper_bound, 'subject, T, Item> From<ScopedBoxStream<'upper_bound, 'subject, Item>> for ScopedOutput<'upper_bound, 'subject, T, Item> {
+    fn from(stream: ScopedBoxStream<'upper_bound, 'subject, Item>) -> Self {
+        Self::Stream(stream)
+    }
+}
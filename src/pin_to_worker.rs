@@ -0,0 +1,52 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_pin_to_worker`](crate::ScopedFutureExt::scope_pin_to_worker) method.
+    ///
+    /// This crate does not integrate with an executor able to pin futures to its workers, so this future only carries the
+    /// hint, which custom executors may read with [`worker_id`](PinToWorker::worker_id), and otherwise polls the
+    /// inner future unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use futures::{stream::FuturesUnordered, StreamExt};
+    /// use scoped_futures::ScopedFutureExt;
+    ///
+    /// let rows = vec![1, 2, 3, 4];
+    /// let futures: Vec<_> = rows.iter().enumerate().map(|(i, row)| async move { row * 10 }.scope_pin_to_worker(i % 2)).collect();
+    /// assert_eq!(vec![0, 1, 0, 1], futures.iter().map(|future| future.worker_id()).collect::<Vec<_>>());
+    /// let mut outputs: Vec<u8> = futures::executor::block_on(futures.into_iter().collect::<FuturesUnordered<_>>().collect());
+    /// outputs.sort();
+    /// assert_eq!(vec![10, 20, 30, 40], outputs);
+    /// # } test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct PinToWorker<Fut> {
+        #[pin]
+        future: Fut,
+        worker_id: usize,
+    }
+}
+
+impl<Fut> PinToWorker<Fut> {
+    pub(crate) fn new(future: Fut, worker_id: usize) -> Self {
+        Self { future, worker_id }
+    }
+
+    /// Returns the worker this future was hinted to run on.
+    pub fn worker_id(&self) -> usize {
+        self.worker_id
+    }
+}
+
+impl<Fut: Future> Future for PinToWorker<Fut> {
+    type Output = Fut::Output;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().future.poll(cx)
+    }
+}
@@ -0,0 +1,75 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+#[cfg(feature = "std")]
+use {core::time::Duration, std::time::Instant};
+
+/// Poll statistics of a future profiled with [`scope_profile`](crate::ScopedFutureExt::scope_profile).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PollStats {
+    /// The number of times the future was polled.
+    pub polls: u64,
+    /// The number of polls which returned [`Poll::Pending`].
+    pub pending: u64,
+    /// The total time spent inside the future's polls, excluding the time spent waiting between them.
+    #[cfg(feature = "std")]
+    pub duration: Duration,
+}
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_profile`](crate::ScopedFutureExt::scope_profile) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use scoped_futures::ScopedFutureExt;
+    /// # use scoped_futures::yield_now;
+    ///
+    /// let rows = vec![1, 2, 3];
+    /// let (sum, stats) = futures::executor::block_on(async {
+    ///     yield_now().await;
+    ///     yield_now().await;
+    ///     rows.iter().sum::<u8>()
+    /// }.scope_profile());
+    /// assert_eq!(6, sum);
+    /// assert_eq!(3, stats.polls);
+    /// assert_eq!(2, stats.pending);
+    /// # } test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Profile<Fut> {
+        #[pin]
+        future: Fut,
+        stats: PollStats,
+    }
+}
+
+impl<Fut> Profile<Fut> {
+    pub(crate) fn new(future: Fut) -> Self {
+        Self { future, stats: PollStats::default() }
+    }
+}
+
+impl<Fut: Future> Future for Profile<Fut> {
+    type Output = (Fut::Output, PollStats);
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        #[cfg(feature = "std")]
+        let started = Instant::now();
+        let poll = this.future.poll(cx);
+        #[cfg(feature = "std")]
+        {
+            this.stats.duration += started.elapsed();
+        }
+        this.stats.polls += 1;
+        match poll {
+            Poll::Ready(output) => Poll::Ready((output, *this.stats)),
+            Poll::Pending => {
+                this.stats.pending += 1;
+                Poll::Pending
+            }
+        }
+    }
+}
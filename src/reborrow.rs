@@ -0,0 +1,109 @@
+use alloc::vec::Vec;
+use core::{
+    cell::{RefCell, RefMut},
+    future::Future,
+    ops::{Deref, DerefMut},
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+use crate::ScopedLocalBoxFuture;
+
+/// A `&mut` borrow shared by several futures within [`scope_reborrow_mut`], each of which reborrows it with
+/// [`lock`](ReborrowMut::lock) for as long as it needs it and relinquishes it when the guard is dropped.
+///
+/// This is local to the task which runs the scope and is not [`Sync`].
+pub struct ReborrowMut<'subject, C: ?Sized> {
+    conn: RefCell<&'subject mut C>,
+    waiters: RefCell<Vec<Waker>>,
+}
+
+impl<'subject, C: ?Sized> ReborrowMut<'subject, C> {
+    /// Waits until no other future holds the borrow, then reborrows it.
+    pub fn lock(&self) -> Lock<'_, 'subject, C> {
+        Lock { reborrow: self }
+    }
+}
+
+/// Future for the [`ReborrowMut::lock`] method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Lock<'a, 'subject, C: ?Sized> {
+    reborrow: &'a ReborrowMut<'subject, C>,
+}
+
+impl<'a, 'subject, C: ?Sized> Future for Lock<'a, 'subject, C> {
+    type Output = ReborrowGuard<'a, 'subject, C>;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let reborrow = self.reborrow;
+        match reborrow.conn.try_borrow_mut() {
+            Ok(conn) => Poll::Ready(ReborrowGuard { conn: Some(conn), waiters: &reborrow.waiters }),
+            Err(_) => {
+                let mut waiters = reborrow.waiters.borrow_mut();
+                if !waiters.iter().any(|waker| waker.will_wake(cx.waker())) {
+                    waiters.push(cx.waker().clone());
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// A reborrow of the connection shared through a [`ReborrowMut`], relinquished when dropped.
+pub struct ReborrowGuard<'a, 'subject, C: ?Sized> {
+    conn: Option<RefMut<'a, &'subject mut C>>,
+    waiters: &'a RefCell<Vec<Waker>>,
+}
+
+impl<C: ?Sized> Deref for ReborrowGuard<'_, '_, C> {
+    type Target = C;
+    fn deref(&self) -> &C {
+        self.conn.as_ref().expect("ReborrowGuard used after release")
+    }
+}
+
+impl<C: ?Sized> DerefMut for ReborrowGuard<'_, '_, C> {
+    fn deref_mut(&mut self) -> &mut C {
+        self.conn.as_mut().expect("ReborrowGuard used after release")
+    }
+}
+
+impl<C: ?Sized> Drop for ReborrowGuard<'_, '_, C> {
+    fn drop(&mut self) {
+        self.conn = None;
+        self.waiters.take().into_iter().for_each(Waker::wake);
+    }
+}
+
+/// Shares the borrowed `conn` between the futures run by `f`, which can each reborrow it across their own yield
+/// points through [`ReborrowMut::lock`] and relinquish it to the others in between.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use scoped_futures::{scope_reborrow_mut, ScopedFutureExt};
+/// # use scoped_futures::yield_now;
+///
+/// pub struct Conn {
+///     log: Vec<&'static str>,
+/// }
+///
+/// let mut conn = Conn { log: vec![] };
+/// futures::executor::block_on(scope_reborrow_mut(&mut conn, |conn| {
+///     let task = move |first, second| async move {
+///         conn.lock().await.log.push(first);
+///         yield_now().await;
+///         conn.lock().await.log.push(second);
+///     };
+///     async move { futures::future::join(task("a1", "a2"), task("b1", "b2")).await; }.scope_boxed_local()
+/// }));
+/// assert_eq!(vec!["a1", "b1", "a2", "b2"], conn.log);
+/// # } #[cfg(feature = "alloc")] test();
+/// ```
+pub async fn scope_reborrow_mut<'subject, C, T, F>(conn: &'subject mut C, f: F) -> T
+where
+    C: ?Sized,
+    F: for<'r> FnOnce(&'r ReborrowMut<'subject, C>) -> ScopedLocalBoxFuture<'subject, 'r, T>,
+{
+    let reborrow = ReborrowMut { conn: RefCell::new(conn), waiters: RefCell::new(Vec::new()) };
+    f(&reborrow).await
+}
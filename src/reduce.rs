@@ -0,0 +1,70 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Future for the [`scoped_reduce`] function.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Reduce<Fut: Future, F> {
+    pending: Vec<Pin<Box<Fut>>>,
+    reducer: F,
+    acc: Option<Fut::Output>,
+}
+
+/// Concurrently awaits each of the provided futures and folds their outputs with `reducer` in completion order,
+/// resolving to [`None`] if no futures were provided.
+///
+/// Outputs are reduced as soon as they are available rather than being collected first.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use scoped_futures::scoped_reduce;
+///
+/// async fn count(rows: &[u8]) -> usize {
+///     rows.len()
+/// }
+///
+/// let tables = vec![vec![1, 2], vec![3], vec![4, 5, 6]];
+/// let total = futures::executor::block_on(scoped_reduce(tables.iter().map(|rows| count(rows)), |a, b| a + b));
+/// assert_eq!(Some(6), total);
+///
+/// let total = futures::executor::block_on(scoped_reduce(tables[..0].iter().map(|rows| count(rows)), |a, b| a + b));
+/// assert_eq!(None, total);
+/// # } #[cfg(feature = "alloc")] test();
+/// ```
+pub fn scoped_reduce<I, F>(futures: I, reducer: F) -> Reduce<I::Item, F>
+where
+    I: IntoIterator<Item: Future>,
+    F: FnMut(<I::Item as Future>::Output, <I::Item as Future>::Output) -> <I::Item as Future>::Output,
+{
+    Reduce { pending: futures.into_iter().map(Box::pin).collect(), reducer, acc: None }
+}
+
+impl<Fut: Future, F> Unpin for Reduce<Fut, F> {}
+
+impl<Fut: Future, F: FnMut(Fut::Output, Fut::Output) -> Fut::Output> Future for Reduce<Fut, F> {
+    type Output = Option<Fut::Output>;
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let mut i = 0;
+        while i < this.pending.len() {
+            match this.pending[i].as_mut().poll(cx) {
+                Poll::Ready(output) => {
+                    drop(this.pending.swap_remove(i));
+                    this.acc = Some(match this.acc.take() {
+                        Some(acc) => (this.reducer)(acc, output),
+                        None => output,
+                    });
+                }
+                Poll::Pending => i += 1,
+            }
+        }
+        match this.pending.is_empty() {
+            true => Poll::Ready(this.acc.take()),
+            false => Poll::Pending,
+        }
+    }
+}
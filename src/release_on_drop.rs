@@ -0,0 +1,90 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_release_on_drop`](crate::ScopedFutureExt::scope_release_on_drop) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use core::{cell::RefCell, future::Future, pin::pin, task::{Context, Waker}};
+    /// use scoped_futures::ScopedFutureExt;
+    ///
+    /// pub struct Conn<'a> {
+    ///     log: &'a RefCell<Vec<&'static str>>,
+    /// }
+    ///
+    /// impl Drop for Conn<'_> {
+    ///     fn drop(&mut self) {
+    ///         self.log.borrow_mut().push("conn dropped");
+    ///     }
+    /// }
+    ///
+    /// async fn query(conn: Conn<'_>, is_ready: bool) -> u8 {
+    ///     if !is_ready {
+    ///         futures::future::pending::<()>().await;
+    ///     }
+    ///     conn.log.borrow_mut().push("query");
+    ///     1
+    /// }
+    ///
+    /// let log = RefCell::new(vec![]);
+    /// let release = || log.borrow_mut().push("released");
+    ///
+    /// let output = futures::executor::block_on(query(Conn { log: &log }, true).scope_release_on_drop(release));
+    /// assert_eq!(1, output);
+    /// assert_eq!(vec!["query", "conn dropped", "released"], log.replace(vec![]));
+    ///
+    /// {
+    ///     let mut cancelled = pin!(query(Conn { log: &log }, false).scope_release_on_drop(release));
+    ///     assert!(cancelled.as_mut().poll(&mut Context::from_waker(Waker::noop())).is_pending());
+    /// }
+    /// assert_eq!(vec!["conn dropped", "released"], log.into_inner());
+    /// # } test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct ReleaseOnDrop<Fut, F>
+    where
+        F: FnOnce(),
+    {
+        #[pin]
+        future: Option<Fut>,
+        release: Option<F>,
+    }
+
+    impl<Fut, F> PinnedDrop for ReleaseOnDrop<Fut, F>
+    where
+        F: FnOnce(),
+    {
+        fn drop(this: Pin<&mut Self>) {
+            let mut this = this.project();
+            this.future.set(None);
+            if let Some(release) = this.release.take() {
+                release();
+            }
+        }
+    }
+}
+
+impl<Fut, F: FnOnce()> ReleaseOnDrop<Fut, F> {
+    pub(crate) fn new(future: Fut, release: F) -> Self {
+        Self { future: Some(future), release: Some(release) }
+    }
+}
+
+impl<Fut: Future, F: FnOnce()> Future for ReleaseOnDrop<Fut, F> {
+    type Output = Fut::Output;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        let future = this.future.as_mut().as_pin_mut().expect("ReleaseOnDrop polled after completion");
+        let output = core::task::ready!(future.poll(cx));
+        this.future.set(None);
+        if let Some(release) = this.release.take() {
+            release();
+        }
+        Poll::Ready(output)
+    }
+}
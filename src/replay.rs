@@ -0,0 +1,65 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A single scripted poll result of a [`ReplayFuture`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ReplayStep<T> {
+    /// Returns [`Poll::Pending`], waking the task straight away if `wake` is set.
+    Pending {
+        /// Whether to wake the task before returning.
+        wake: bool,
+    },
+    /// Returns [`Poll::Ready`] with the provided output.
+    Ready(T),
+}
+
+pin_project_lite::pin_project! {
+    /// A future which replays a scripted sequence of poll results, for deterministically testing which branch
+    /// of a combinator completes when.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "test-util")] fn test() {
+    /// use futures::future::{select, Either};
+    /// use scoped_futures::{ReplayFuture, ReplayStep};
+    ///
+    /// let slow = || ReplayFuture::new([ReplayStep::Pending { wake: true }, ReplayStep::Ready("slow")]);
+    /// let fast = || ReplayFuture::new([ReplayStep::Ready("fast")]);
+    /// futures::executor::block_on(async {
+    ///     let Either::Right((output, _)) = select(slow(), fast()).await else { panic!() };
+    ///     assert_eq!("fast", output);
+    ///     let Either::Left((output, _)) = select(fast(), slow()).await else { panic!() };
+    ///     assert_eq!("fast", output);
+    /// });
+    /// # } #[cfg(feature = "test-util")] test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct ReplayFuture<I> {
+        steps: I,
+    }
+}
+
+impl<I: Iterator> ReplayFuture<I> {
+    /// Creates a future which returns each of `steps` in turn when polled.
+    pub fn new(steps: impl IntoIterator<IntoIter = I>) -> Self {
+        Self { steps: steps.into_iter() }
+    }
+}
+
+impl<T, I: Iterator<Item = ReplayStep<T>>> Future for ReplayFuture<I> {
+    type Output = T;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.project().steps.next().expect("ReplayFuture polled after its script ran out") {
+            ReplayStep::Pending { wake } => {
+                if wake {
+                    cx.waker().wake_by_ref();
+                }
+                Poll::Pending
+            }
+            ReplayStep::Ready(output) => Poll::Ready(output),
+        }
+    }
+}
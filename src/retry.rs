@@ -0,0 +1,95 @@
+use core::{future::Future, time::Duration};
+
+use crate::Timer;
+
+/// How often and how long [`scope_retry_jitter`] waits between attempts.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first one.
+    pub max_attempts: u32,
+    /// The backoff before the first retry, which doubles with every retry after it.
+    pub base_delay: Duration,
+    /// The upper bound on the backoff.
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Returns the backoff before retrying after the failed attempt numbered `attempt`, counting from zero.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        self.base_delay.saturating_mul(2u32.saturating_pow(attempt)).min(self.max_delay)
+    }
+}
+
+/// A source of randomness for jittering retry delays, which keeps this crate independent of any particular RNG.
+pub trait JitterRng {
+    /// Returns the next random number.
+    fn next_u64(&mut self) -> u64;
+}
+
+impl<F: FnMut() -> u64> JitterRng for F {
+    fn next_u64(&mut self) -> u64 {
+        self()
+    }
+}
+
+/// Awaits the futures made by `make_fut` for successive attempts until one succeeds or `policy` runs out of attempts,
+/// resolving to the last attempt's result. Between attempts it sleeps on `timer` for a random duration drawn from `rng`
+/// between zero and the policy's backoff, so that concurrent retries spread out instead of failing together again.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use core::{cell::{Cell, RefCell}, time::Duration};
+/// use scoped_futures::{scope_retry_jitter, RetryPolicy};
+///
+/// async fn commit(attempts: &Cell<u32>) -> Result<u32, &'static str> {
+///     attempts.set(attempts.get() + 1);
+///     if attempts.get() < 3 { Err("serialization failure") } else { Ok(attempts.get()) }
+/// }
+///
+/// let policy = RetryPolicy { max_attempts: 5, base_delay: Duration::from_millis(100), max_delay: Duration::from_secs(1) };
+/// let mut seed = 0u64;
+/// let rng = || {
+///     seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+///     seed
+/// };
+/// let sleeps = RefCell::new(vec![]);
+/// let timer = |duration| {
+///     sleeps.borrow_mut().push(duration);
+///     futures::future::ready(())
+/// };
+///
+/// let attempts = Cell::new(0);
+/// let output = futures::executor::block_on(scope_retry_jitter(policy, rng, timer, |_| commit(&attempts)));
+/// assert_eq!(Ok(3), output);
+/// let sleeps = sleeps.into_inner();
+/// assert_eq!(2, sleeps.len());
+/// assert!(sleeps.iter().enumerate().all(|(attempt, sleep)| *sleep <= policy.backoff(attempt as u32)));
+/// assert_ne!(sleeps, vec![policy.backoff(0), policy.backoff(1)]);
+/// # } test();
+/// ```
+pub async fn scope_retry_jitter<T, E, R, Ti, F, Fut>(policy: RetryPolicy, mut rng: R, timer: Ti, mut make_fut: F) -> Result<T, E>
+where
+    R: JitterRng,
+    Ti: Timer,
+    F: FnMut(u32) -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let mut attempt = 0;
+    loop {
+        let error = match make_fut(attempt).await {
+            Ok(output) => return Ok(output),
+            Err(error) => error,
+        };
+        if attempt + 1 >= policy.max_attempts {
+            return Err(error);
+        }
+        let backoff = u64::try_from(policy.backoff(attempt).as_nanos()).unwrap_or(u64::MAX);
+        let jittered = match backoff.checked_add(1) {
+            Some(bound) => rng.next_u64() % bound,
+            None => rng.next_u64(),
+        };
+        timer.sleep(Duration::from_nanos(jittered)).await;
+        attempt += 1;
+    }
+}
@@ -0,0 +1,95 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A [`Future`] which can report how far it has gotten, so that this can be recovered if it is cancelled.
+pub trait Savepoint: Future {
+    /// The state recorded by a savepoint.
+    type Savepoint;
+
+    /// Returns the state reached so far.
+    fn savepoint(self: Pin<&mut Self>) -> Self::Savepoint;
+}
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_save_point`](crate::ScopedFutureExt::scope_save_point) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use core::{future::Future, pin::{pin, Pin}, task::{Context, Poll, Waker}};
+    /// use scoped_futures::{ScopedFutureExt, Savepoint};
+    ///
+    /// // inserts one row per poll
+    /// pub struct Insert<'a> {
+    ///     rows: &'a [u8],
+    ///     inserted: usize,
+    /// }
+    ///
+    /// impl Future for Insert<'_> {
+    ///     type Output = usize;
+    ///     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<usize> {
+    ///         if self.inserted == self.rows.len() {
+    ///             return Poll::Ready(self.inserted);
+    ///         }
+    ///         self.inserted += 1;
+    ///         cx.waker().wake_by_ref();
+    ///         Poll::Pending
+    ///     }
+    /// }
+    ///
+    /// impl<'a> Savepoint for Insert<'a> {
+    ///     type Savepoint = &'a [u8];
+    ///     fn savepoint(self: Pin<&mut Self>) -> &'a [u8] {
+    ///         &self.rows[..self.inserted]
+    ///     }
+    /// }
+    ///
+    /// let rows = vec![1, 2, 3, 4];
+    /// let mut insert = pin!(Insert { rows: &rows, inserted: 0 }.scope_save_point());
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// assert!(insert.as_mut().poll(&mut cx).is_pending());
+    /// assert!(insert.as_mut().poll(&mut cx).is_pending());
+    /// // cancel the insert and find out which rows made it in
+    /// assert_eq!(Some(&[1, 2][..]), insert.as_mut().savepoint());
+    ///
+    /// let mut insert = pin!(Insert { rows: &rows[..1], inserted: 0 }.scope_save_point());
+    /// assert_eq!(1, futures::executor::block_on(insert.as_mut()));
+    /// assert_eq!(None, insert.as_mut().savepoint());
+    /// # } test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct WithSavepoint<Fut> {
+        #[pin]
+        future: Fut,
+        done: bool,
+    }
+}
+
+impl<Fut: Savepoint> WithSavepoint<Fut> {
+    pub(crate) fn new(future: Fut) -> Self {
+        Self { future, done: false }
+    }
+
+    /// Returns the state reached by the inner future so far, or [`None`] if it has already completed.
+    pub fn savepoint(self: Pin<&mut Self>) -> Option<Fut::Savepoint> {
+        let this = self.project();
+        match this.done {
+            true => None,
+            false => Some(this.future.savepoint()),
+        }
+    }
+}
+
+impl<Fut: Savepoint> Future for WithSavepoint<Fut> {
+    type Output = Fut::Output;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        assert!(!*this.done, "WithSavepoint polled after completion");
+        let output = core::task::ready!(this.future.poll(cx));
+        *this.done = true;
+        Poll::Ready(output)
+    }
+}
@@ -0,0 +1,73 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_core::Stream;
+
+/// The output of [`scope_select_recv`], identifying which branch fired first.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SelectRecvOutput<T, M> {
+    /// The future completed first.
+    Future(T),
+    /// A message was received first, or [`None`] if the channel was closed.
+    Message(Option<M>),
+}
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_select_recv`] function.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct SelectRecv<'subject, Fut, Rx> {
+        #[pin]
+        future: Fut,
+        rx: &'subject mut Rx,
+    }
+}
+
+/// Races `future` against receiving the next message from the borrowed channel receiver `rx`.
+/// Any [`Stream`] can be used as the receiver, so this works with the channels of any runtime.
+///
+/// The future is polled first when both are ready. To keep awaiting the future after a message wins,
+/// pass it in by mutable reference.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use futures::channel::mpsc;
+/// use scoped_futures::{scope_select_recv, SelectRecvOutput};
+///
+/// futures::executor::block_on(async {
+///     let mut work_done = 0;
+///     let (tx, mut rx) = mpsc::unbounded::<&str>();
+///
+///     let output = scope_select_recv(async { work_done += 1; work_done }, &mut rx).await;
+///     assert_eq!(SelectRecvOutput::Future(1), output);
+///
+///     tx.unbounded_send("stop").unwrap();
+///     let output = scope_select_recv(futures::future::pending::<()>(), &mut rx).await;
+///     assert_eq!(SelectRecvOutput::Message(Some("stop")), output);
+///
+///     drop(tx);
+///     let output = scope_select_recv(futures::future::pending::<()>(), &mut rx).await;
+///     assert_eq!(SelectRecvOutput::Message(None), output);
+/// });
+/// # } test();
+/// ```
+pub fn scope_select_recv<'subject, Fut, Rx>(future: Fut, rx: &'subject mut Rx) -> SelectRecv<'subject, Fut, Rx>
+where
+    Fut: Future + 'subject,
+    Rx: Stream + Unpin,
+{
+    SelectRecv { future, rx }
+}
+
+impl<Fut: Future, Rx: Stream + Unpin> Future for SelectRecv<'_, Fut, Rx> {
+    type Output = SelectRecvOutput<Fut::Output, Rx::Item>;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        if let Poll::Ready(output) = this.future.poll(cx) {
+            return Poll::Ready(SelectRecvOutput::Future(output));
+        }
+        Pin::new(&mut **this.rx).poll_next(cx).map(SelectRecvOutput::Message)
+    }
+}
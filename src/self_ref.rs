@@ -0,0 +1,80 @@
+use alloc::boxed::Box;
+use core::{fmt, marker::PhantomData, ops::Deref, ptr::NonNull};
+
+/// An owned value bundled with a reference borrowed from it, so that a scoped future can return data together with
+/// a view into that data, such as a buffer together with the part of it which was parsed.
+///
+/// The owner is moved onto the heap and never moved or handed out mutably until the bundle is dropped, which
+/// keeps the address the reference points into stable. The owner may itself borrow for `'subject`, so the
+/// bundle cannot outlive the scope it was created in.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use scoped_futures::ScopedSelfRef;
+///
+/// async fn read_header(source: &[u8]) -> ScopedSelfRef<'_, Vec<u8>, [u8]> {
+///     let buffer = source.to_vec();
+///     ScopedSelfRef::new(buffer, |buffer| buffer.split(|byte| *byte == b'\n').next().unwrap_or_default())
+/// }
+///
+/// let source = b"id,name\n1,ann\n".to_vec();
+/// futures::executor::block_on(async {
+///     let header = read_header(&source).await;
+///     assert_eq!(b"id,name", &*header);
+///     assert_eq!(14, header.owner().len());
+/// });
+/// # } #[cfg(feature = "alloc")] test();
+/// ```
+pub struct ScopedSelfRef<'subject, O: 'subject, U: ?Sized> {
+    reference: NonNull<U>,
+    owner: NonNull<O>,
+    scope: PhantomData<(&'subject (), Box<O>, &'subject U)>,
+}
+
+impl<'subject, O: 'subject, U: ?Sized> ScopedSelfRef<'subject, O, U> {
+    /// Moves `owner` onto the heap and borrows from it with `f`.
+    pub fn new(owner: O, f: impl for<'a> FnOnce(&'a O) -> &'a U) -> Self {
+        let owner = NonNull::from(Box::leak(Box::new(owner)));
+        // SAFETY: `owner` was just leaked, so it is valid and nothing else has access to it
+        let reference = NonNull::from(f(unsafe { owner.as_ref() }));
+        Self { reference, owner, scope: PhantomData }
+    }
+
+    /// Returns the reference borrowed from the owner.
+    pub fn get(&self) -> &U {
+        // SAFETY: the owner outlives `self` and is only ever accessed immutably, so the reference is still valid
+        unsafe { self.reference.as_ref() }
+    }
+
+    /// Returns the owner.
+    pub fn owner(&self) -> &O {
+        // SAFETY: the owner is only freed in `drop`
+        unsafe { self.owner.as_ref() }
+    }
+}
+
+impl<O, U: ?Sized> Deref for ScopedSelfRef<'_, O, U> {
+    type Target = U;
+    fn deref(&self) -> &U {
+        self.get()
+    }
+}
+
+impl<O, U: ?Sized + fmt::Debug> fmt::Debug for ScopedSelfRef<'_, O, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ScopedSelfRef").field(&self.get()).finish()
+    }
+}
+
+impl<O, U: ?Sized> Drop for ScopedSelfRef<'_, O, U> {
+    fn drop(&mut self) {
+        // SAFETY: the owner was leaked from a box in `new`, and the reference into it is not used after this point
+        drop(unsafe { Box::from_raw(self.owner.as_ptr()) });
+    }
+}
+
+// SAFETY: the bundle owns an `O` and hands out shared references to `O` and `U`
+unsafe impl<O: Send + Sync, U: ?Sized + Sync> Send for ScopedSelfRef<'_, O, U> {}
+// SAFETY: the bundle only hands out shared references to `O` and `U`
+unsafe impl<O: Sync, U: ?Sized + Sync> Sync for ScopedSelfRef<'_, O, U> {}
@@ -0,0 +1,47 @@
+use crate::{ScopedBoxFuture, ScopedBoxStream, ScopedLocalBoxFuture, ScopedLocalBoxStream};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Exposes whether a boxed scoped future or stream type is [`Send`] at compile time,
+/// so generic code can branch on it, e.g. to choose a spawn strategy.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use scoped_futures::{ScopedBoxFuture, ScopedLocalBoxFuture, ScopedSendness};
+///
+/// fn spawn_strategy<Fut: ScopedSendness>() -> &'static str {
+///     if Fut::IS_SEND { "spawn" } else { "spawn_local" }
+/// }
+///
+/// assert!(<ScopedBoxFuture<'static, 'static, ()> as ScopedSendness>::IS_SEND);
+/// assert!(!<ScopedLocalBoxFuture<'static, 'static, ()> as ScopedSendness>::IS_SEND);
+/// assert_eq!("spawn_local", spawn_strategy::<ScopedLocalBoxFuture<'_, '_, u8>>());
+/// # } #[cfg(feature = "alloc")] test();
+/// ```
+pub trait ScopedSendness: sealed::Sealed {
+    /// Whether this type is [`Send`].
+    const IS_SEND: bool;
+}
+
+impl<T> sealed::Sealed for ScopedBoxFuture<'_, '_, T> {}
+impl<T> ScopedSendness for ScopedBoxFuture<'_, '_, T> {
+    const IS_SEND: bool = true;
+}
+
+impl<T> sealed::Sealed for ScopedLocalBoxFuture<'_, '_, T> {}
+impl<T> ScopedSendness for ScopedLocalBoxFuture<'_, '_, T> {
+    const IS_SEND: bool = false;
+}
+
+impl<T> sealed::Sealed for ScopedBoxStream<'_, '_, T> {}
+impl<T> ScopedSendness for ScopedBoxStream<'_, '_, T> {
+    const IS_SEND: bool = true;
+}
+
+impl<T> sealed::Sealed for ScopedLocalBoxStream<'_, '_, T> {}
+impl<T> ScopedSendness for ScopedLocalBoxStream<'_, '_, T> {
+    const IS_SEND: bool = false;
+}
@@ -0,0 +1,86 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project_lite::pin_project! {
+    #[project = SequenceProj]
+    #[project_replace = SequenceProjReplace]
+    enum State<First: Future, F, Second: Future> {
+        First { #[pin] first: First, second_fn: F },
+        Second { first_output: Option<First::Output>, #[pin] second: Second },
+        Done,
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_sequence`] function.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Sequence<First: Future, F, Second: Future> {
+        #[pin]
+        state: State<First, F, Second>,
+    }
+}
+
+/// Runs `first` to completion before creating the second future from its output with `second_fn`,
+/// resolving to the outputs of both.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use core::cell::RefCell;
+/// use scoped_futures::scope_sequence;
+///
+/// let log = RefCell::new(vec![]);
+/// let outputs = futures::executor::block_on(scope_sequence(
+///     async {
+///         log.borrow_mut().push("insert");
+///         1
+///     },
+///     |id| {
+///         let id = *id;
+///         let log = &log;
+///         async move {
+///             assert_eq!(vec!["insert"], *log.borrow());
+///             log.borrow_mut().push("update");
+///             id + 1
+///         }
+///     },
+/// ));
+/// assert_eq!((1, 2), outputs);
+/// assert_eq!(vec!["insert", "update"], log.into_inner());
+/// # } test();
+/// ```
+pub fn scope_sequence<First, F, Second>(first: First, second_fn: F) -> Sequence<First, F, Second>
+where
+    First: Future,
+    F: FnOnce(&First::Output) -> Second,
+    Second: Future,
+{
+    Sequence { state: State::First { first, second_fn } }
+}
+
+impl<First: Future, F: FnOnce(&First::Output) -> Second, Second: Future> Future for Sequence<First, F, Second> {
+    type Output = (First::Output, Second::Output);
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.project().state;
+        loop {
+            match state.as_mut().project() {
+                SequenceProj::First { first, .. } => {
+                    let first_output = core::task::ready!(first.poll(cx));
+                    let SequenceProjReplace::First { second_fn, .. } = state.as_mut().project_replace(State::Done) else { unreachable!() };
+                    let second = second_fn(&first_output);
+                    state.set(State::Second { first_output: Some(first_output), second });
+                }
+                SequenceProj::Second { first_output, second } => {
+                    let second_output = core::task::ready!(second.poll(cx));
+                    let first_output = first_output.take().expect("Sequence polled after completion");
+                    state.set(State::Done);
+                    return Poll::Ready((first_output, second_output));
+                }
+                SequenceProj::Done => panic!("Sequence polled after completion"),
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use alloc::vec::Vec;
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_serialize_with`](crate::ScopedFutureExt::scope_serialize_with) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use std::io::Write;
+    /// use scoped_futures::ScopedFutureExt;
+    ///
+    /// async fn select<'a>(table: &'a [(&'a str, u8)]) -> Vec<&'a (&'a str, u8)> {
+    ///     table.iter().filter(|(_, age)| *age >= 18).collect()
+    /// }
+    ///
+    /// let table = vec![("ann", 31), ("bob", 12)];
+    /// let body = futures::executor::block_on(select(&table).scope_serialize_with(|rows, buffer| {
+    ///     rows.iter().try_for_each(|(name, age)| writeln!(buffer, "{name},{age}"))
+    /// }));
+    /// assert_eq!(b"ann,31\n", &body.unwrap()[..]);
+    /// # } #[cfg(feature = "std")] test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct SerializeWith<Fut, F> {
+        #[pin]
+        future: Fut,
+        serialize: Option<F>,
+    }
+}
+
+impl<Fut, F> SerializeWith<Fut, F> {
+    pub(crate) fn new(future: Fut, serialize: F) -> Self {
+        Self { future, serialize: Some(serialize) }
+    }
+}
+
+impl<E, Fut: Future, F: FnOnce(&Fut::Output, &mut Vec<u8>) -> Result<(), E>> Future for SerializeWith<Fut, F> {
+    type Output = Result<Vec<u8>, E>;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let output = core::task::ready!(this.future.poll(cx));
+        let serialize = this.serialize.take().expect("SerializeWith polled after completion");
+        let mut buffer = Vec::new();
+        Poll::Ready(serialize(&output, &mut buffer).map(|()| buffer))
+    }
+}
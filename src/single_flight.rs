@@ -0,0 +1,139 @@
+use crate::fan_out::FanOutWaker;
+use alloc::{boxed::Box, collections::BTreeMap, rc::Rc, sync::Arc};
+use core::{
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+struct Flight<'subject, T> {
+    future: Option<Pin<Box<dyn Future<Output = T> + 'subject>>>,
+    output: Option<T>,
+    wakers: Arc<FanOutWaker>,
+    waker: Waker,
+}
+
+type SharedFlight<'subject, T> = Rc<RefCell<Flight<'subject, T>>>;
+
+/// Deduplicates concurrent borrowing futures by key, so that only one future runs per key at a time
+/// and every caller for that key receives a clone of its output.
+///
+/// The group and its futures share their flights through [`Rc`], so they are neither [`Send`] nor [`Sync`] and are
+/// local to the task which joins the deduplicated futures. To box them under the same scope, use
+/// [`scope_boxed_local`](crate::ScopedFutureExt::scope_boxed_local).
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use core::{cell::Cell, future::Future, task::{Context, Waker}};
+/// use scoped_futures::{LocalSingleFlightGroup, ScopedFutureExt, ScopedLocalBoxFuture};
+///
+/// pub struct Db {
+///     queries: Cell<u8>,
+/// }
+///
+/// async fn load(db: &Db, id: u8) -> String {
+///     db.queries.set(db.queries.get() + 1);
+///     format!("user {id}")
+/// }
+///
+/// futures::executor::block_on(async {
+///     let db = Db { queries: Cell::new(0) };
+///     let group = LocalSingleFlightGroup::new();
+///     let (first, second) = futures::future::join(
+///         group.scope_single_flight(1, || load(&db, 1)),
+///         group.scope_single_flight(1, || load(&db, 1)),
+///     ).await;
+///     assert_eq!("user 1", first);
+///     assert_eq!(first, second);
+///     assert_eq!(1, db.queries.get());
+///
+///     // completed flights are not cached
+///     let flight: ScopedLocalBoxFuture<'_, '_, String> = group.scope_single_flight(1, || load(&db, 1)).scope_boxed_local();
+///     flight.await;
+///     assert_eq!(2, db.queries.get());
+///
+///     // waiting callers are still woken once the caller which last polled the flight is dropped
+///     let (tx, rx) = futures::channel::oneshot::channel::<()>();
+///     let waiter = group.scope_single_flight(2, || async { rx.await.unwrap(); load(&db, 2).await });
+///     let mut dropped = Box::pin(group.scope_single_flight(2, || load(&db, 2)));
+///     let (output, ()) = futures::future::join(waiter, async move {
+///         assert!(dropped.as_mut().poll(&mut Context::from_waker(Waker::noop())).is_pending());
+///         drop(dropped);
+///         tx.send(()).unwrap();
+///     }).await;
+///     assert_eq!("user 2", output);
+/// });
+/// # } #[cfg(feature = "alloc")] test();
+/// ```
+pub struct LocalSingleFlightGroup<'subject, K, T> {
+    flights: RefCell<BTreeMap<K, SharedFlight<'subject, T>>>,
+}
+
+impl<K, T> Default for LocalSingleFlightGroup<'_, K, T> {
+    fn default() -> Self {
+        Self { flights: RefCell::new(BTreeMap::new()) }
+    }
+}
+
+impl<'subject, K: Ord + Clone, T: Clone> LocalSingleFlightGroup<'subject, K, T> {
+    /// Creates an empty group.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs the future returned by `make_fut` unless a future for `key` is already in flight,
+    /// in which case the output of the in flight future is awaited instead.
+    pub fn scope_single_flight<F, Fut>(&self, key: K, make_fut: F) -> LocalSingleFlight<'_, 'subject, K, T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = T> + 'subject,
+    {
+        let in_flight = self.flights.borrow().get(&key).cloned();
+        let flight = in_flight.unwrap_or_else(|| {
+            // `make_fut` may itself join flights of this group, so it is called before the group is borrowed
+            let future: Pin<Box<dyn Future<Output = T> + 'subject>> = Box::pin(make_fut());
+            let wakers = Arc::new(FanOutWaker::default());
+            let waker = Waker::from(wakers.clone());
+            let flight = Rc::new(RefCell::new(Flight { future: Some(future), output: None, wakers, waker }));
+            self.flights.borrow_mut().insert(key.clone(), flight.clone());
+            flight
+        });
+        LocalSingleFlight { group: self, key, flight }
+    }
+}
+
+/// Future for the [`LocalSingleFlightGroup::scope_single_flight`] method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct LocalSingleFlight<'group, 'subject, K, T> {
+    group: &'group LocalSingleFlightGroup<'subject, K, T>,
+    key: K,
+    flight: SharedFlight<'subject, T>,
+}
+
+impl<K: Ord, T: Clone> Future for LocalSingleFlight<'_, '_, K, T> {
+    type Output = T;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut flight = self.flight.borrow_mut();
+        if let Some(output) = &flight.output {
+            return Poll::Ready(output.clone());
+        }
+        flight.wakers.register(cx.waker());
+        let flight = &mut *flight;
+        let future = flight.future.as_mut().expect("in flight future is only taken once complete");
+        match future.as_mut().poll(&mut Context::from_waker(&flight.waker)) {
+            Poll::Ready(output) => {
+                flight.future = None;
+                flight.output = Some(output.clone());
+                flight.waker.wake_by_ref();
+                let mut flights = self.group.flights.borrow_mut();
+                if flights.get(&self.key).is_some_and(|current| Rc::ptr_eq(current, &self.flight)) {
+                    flights.remove(&self.key);
+                }
+                Poll::Ready(output)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use core::{
+    cell::UnsafeCell,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// A minimal lock for state shared between the handles of a combinator, which keeps them [`Send`] and [`Sync`]
+/// without depending on `std`. It is only ever held for short, non-reentrant critical sections, so user code
+/// such as wakers must never run while it is held.
+#[derive(Default)]
+pub(crate) struct SpinLock<T> {
+    locked: AtomicBool,
+    value: UnsafeCell<T>,
+}
+
+// SAFETY: `value` is only accessed while `locked` is held, which hands it to one thread at a time.
+unsafe impl<T: Send> Sync for SpinLock<T> {}
+
+struct Unlock<'lock>(&'lock AtomicBool);
+
+impl Drop for Unlock<'_> {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
+}
+
+impl<T> SpinLock<T> {
+    pub(crate) const fn new(value: T) -> Self {
+        Self { locked: AtomicBool::new(false), value: UnsafeCell::new(value) }
+    }
+
+    /// Runs `f` with exclusive access to the locked value.
+    pub(crate) fn with<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        while self.locked.compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed).is_err() {
+            core::hint::spin_loop();
+        }
+        let _unlock = Unlock(&self.locked);
+        // SAFETY: `locked` grants exclusive access to `value` until `_unlock` is dropped.
+        f(unsafe { &mut *self.value.get() })
+    }
+}
@@ -0,0 +1,91 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::maybe_done::MaybeDone;
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_split_borrow`] function.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct SplitBorrow<Left: Future, Right: Future> {
+        #[pin]
+        left: MaybeDone<Left>,
+        #[pin]
+        right: MaybeDone<Right>,
+    }
+}
+
+/// Splits a mutable borrow of `conn` into two disjoint borrows using `split` and concurrently runs
+/// a scoped future over each of them, resolving to both outputs once both have completed.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use scoped_futures::scope_split_borrow;
+/// # use scoped_futures::yield_now;
+///
+/// pub struct Conn {
+///     reads: Vec<u8>,
+///     writes: Vec<u8>,
+/// }
+///
+/// futures::executor::block_on(async {
+///     let mut conn = Conn { reads: vec![], writes: vec![] };
+///     let (read, written) = scope_split_borrow(
+///         &mut conn,
+///         |conn| (&mut conn.reads, &mut conn.writes),
+///         |reads| async move {
+///             reads.push(1);
+///             yield_now().await;
+///             reads.push(2);
+///             reads.len()
+///         },
+///         |writes| async move {
+///             writes.push(3);
+///             writes.len()
+///         },
+///     )
+///     .await;
+///     assert_eq!((2, 1), (read, written));
+///     assert_eq!(vec![1, 2], conn.reads);
+///     assert_eq!(vec![3], conn.writes);
+/// });
+/// # } test();
+/// ```
+pub fn scope_split_borrow<'subject, Conn, Left, Right, Split, LeftFn, RightFn, LeftFut, RightFut>(
+    conn: &'subject mut Conn,
+    split: Split,
+    left: LeftFn,
+    right: RightFn,
+) -> SplitBorrow<LeftFut, RightFut>
+where
+    Conn: ?Sized,
+    Left: ?Sized + 'subject,
+    Right: ?Sized + 'subject,
+    Split: FnOnce(&'subject mut Conn) -> (&'subject mut Left, &'subject mut Right),
+    LeftFn: FnOnce(&'subject mut Left) -> LeftFut,
+    RightFn: FnOnce(&'subject mut Right) -> RightFut,
+    LeftFut: Future + 'subject,
+    RightFut: Future + 'subject,
+{
+    let (left_borrow, right_borrow) = split(conn);
+    SplitBorrow { left: MaybeDone::new(left(left_borrow)), right: MaybeDone::new(right(right_borrow)) }
+}
+
+impl<Left: Future, Right: Future> Future for SplitBorrow<Left, Right> {
+    type Output = (Left::Output, Right::Output);
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        let left_done = this.left.as_mut().poll_done(cx);
+        let right_done = this.right.as_mut().poll_done(cx);
+        if !(left_done && right_done) {
+            return Poll::Pending;
+        }
+        match (this.left.take_output(), this.right.take_output()) {
+            (Some(left), Some(right)) => Poll::Ready((left, right)),
+            _ => panic!("SplitBorrow polled after completion"),
+        }
+    }
+}
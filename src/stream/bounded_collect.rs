@@ -0,0 +1,75 @@
+use alloc::vec::Vec;
+use core::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_core::Stream;
+
+/// The error returned by [`scope_bounded_collect`] when the collected items exceed the byte limit.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct LimitExceeded {
+    /// The byte limit which was exceeded.
+    pub max_bytes: usize,
+}
+
+impl fmt::Display for LimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "collected items exceeded {} bytes", self.max_bytes)
+    }
+}
+
+impl core::error::Error for LimitExceeded {}
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_bounded_collect`] function.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct BoundedCollect<S: Stream> {
+        #[pin]
+        stream: S,
+        items: Vec<S::Item>,
+        bytes: usize,
+        max_bytes: usize,
+    }
+}
+
+/// Collects the items of `stream` into a [`Vec`], failing as soon as the total length of the items' bytes exceeds `max_bytes`.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use scoped_futures::{scope_bounded_collect, LimitExceeded};
+///
+/// let rows = vec!["ann", "bob", "cat"];
+/// futures::executor::block_on(async {
+///     let collected = scope_bounded_collect(futures::stream::iter(rows.iter().copied()), 9).await;
+///     assert_eq!(Ok(vec!["ann", "bob", "cat"]), collected);
+///     let collected = scope_bounded_collect(futures::stream::iter(rows.iter().copied()), 8).await;
+///     assert_eq!(Err(LimitExceeded { max_bytes: 8 }), collected);
+/// });
+/// # } #[cfg(feature = "alloc")] test();
+/// ```
+pub fn scope_bounded_collect<S>(stream: S, max_bytes: usize) -> BoundedCollect<S>
+where
+    S: Stream<Item: AsRef<[u8]>>,
+{
+    BoundedCollect { stream, items: Vec::new(), bytes: 0, max_bytes }
+}
+
+impl<S: Stream<Item: AsRef<[u8]>>> Future for BoundedCollect<S> {
+    type Output = Result<Vec<S::Item>, LimitExceeded>;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            let Some(item) = core::task::ready!(this.stream.as_mut().poll_next(cx)) else {
+                return Poll::Ready(Ok(core::mem::take(this.items)));
+            };
+            *this.bytes = this.bytes.saturating_add(item.as_ref().len());
+            if *this.bytes > *this.max_bytes {
+                return Poll::Ready(Err(LimitExceeded { max_bytes: *this.max_bytes }));
+            }
+            this.items.push(item);
+        }
+    }
+}
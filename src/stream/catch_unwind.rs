@@ -0,0 +1,53 @@
+use core::{
+    any::Any,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_core::Stream;
+use std::{boxed::Box, panic::AssertUnwindSafe};
+
+/// The payload of a panic caught by [`scoped_catch_unwind_stream`].
+pub type Panic = Box<dyn Any + Send>;
+
+pin_project_lite::pin_project! {
+    /// Stream for the [`scoped_catch_unwind_stream`] function.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct CatchUnwindStream<S> {
+        #[pin]
+        stream: S,
+    }
+}
+
+/// Yields the items of `stream` as [`Ok`], turning any panic raised while polling it into an [`Err`] item
+/// and carrying on with the next item. The stream must tolerate being polled again after it panicked.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use futures::StreamExt;
+/// use scoped_futures::scoped_catch_unwind_stream;
+///
+/// let rows = vec![1, 0, 2];
+/// let stream = futures::stream::iter(&rows).map(|row| 2 / row);
+/// let results: Vec<_> = futures::executor::block_on(scoped_catch_unwind_stream(stream).collect());
+/// assert!(matches!(results[..], [Ok(2), Err(_), Ok(1)]));
+/// # } #[cfg(feature = "std")] test();
+/// ```
+pub fn scoped_catch_unwind_stream<S: Stream>(stream: S) -> CatchUnwindStream<S> {
+    CatchUnwindStream { stream }
+}
+
+impl<S: Stream> Stream for CatchUnwindStream<S> {
+    type Item = Result<S::Item, Panic>;
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let stream = self.project().stream;
+        match std::panic::catch_unwind(AssertUnwindSafe(|| stream.poll_next(cx))) {
+            Ok(poll) => poll.map(|item| item.map(Ok)),
+            Err(panic) => Poll::Ready(Some(Err(panic))),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
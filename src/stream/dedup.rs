@@ -0,0 +1,55 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_core::Stream;
+
+pin_project_lite::pin_project! {
+    /// Stream for the [`scoped_dedup`] function.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Dedup<S: Stream> {
+        #[pin]
+        stream: S,
+        last: Option<S::Item>,
+    }
+}
+
+/// Drops items of `stream` which are equal to the item immediately before them.
+/// Every other item is yielded as soon as it is available.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use futures::StreamExt;
+/// use scoped_futures::scoped_dedup;
+///
+/// let changes = vec![1, 1, 2, 2, 1];
+/// let deduped: Vec<&u8> = futures::executor::block_on(scoped_dedup(futures::stream::iter(&changes)).collect());
+/// assert_eq!(vec![&1, &2, &1], deduped);
+/// # } test();
+/// ```
+pub fn scoped_dedup<S>(stream: S) -> Dedup<S>
+where
+    S: Stream<Item: PartialEq + Clone>,
+{
+    Dedup { stream, last: None }
+}
+
+impl<S: Stream<Item: PartialEq + Clone>> Stream for Dedup<S> {
+    type Item = S::Item;
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            let Some(item) = core::task::ready!(this.stream.as_mut().poll_next(cx)) else { return Poll::Ready(None) };
+            if this.last.as_ref() != Some(&item) {
+                *this.last = Some(item.clone());
+                return Poll::Ready(Some(item));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        (lower.min(1), upper)
+    }
+}
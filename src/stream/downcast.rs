@@ -0,0 +1,45 @@
+use alloc::boxed::Box;
+use core::{any::TypeId, pin::Pin};
+use futures_core::Stream;
+
+use crate::ScopedBoxStream;
+
+/// Recovers the concrete type of a [`ScopedBoxStream`].
+///
+/// [`TypeId`] only distinguishes `'static` types, and comparing the type ids of two streams which merely differ
+/// in the lifetimes of their borrows would let a stream borrowing short-lived data be recovered as one which
+/// outlives it. Downcasting is therefore only available for boxed streams whose scope is `'static`; streams
+/// which borrow from an enclosing scope have to be kept as trait objects.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use futures::stream::{self, Iter, StreamExt};
+/// use scoped_futures::{ScopedBoxStreamDowncast, ScopedStreamExt};
+///
+/// let stream = stream::iter(vec![1, 2]).scope_boxed();
+/// let Err(stream) = stream.downcast::<stream::Once<futures::future::Ready<u8>>>() else { panic!() };
+/// let Ok(stream) = stream.downcast::<Iter<std::vec::IntoIter<u8>>>() else { panic!() };
+/// assert_eq!(vec![1, 2], futures::executor::block_on(stream.collect::<Vec<_>>()));
+/// # } #[cfg(feature = "alloc")] test();
+/// ```
+pub trait ScopedBoxStreamDowncast: Sized {
+    /// The items of the boxed stream.
+    type Item;
+
+    /// Returns the boxed stream as an `S` if that is its concrete type, or returns it unchanged otherwise.
+    fn downcast<S: Stream<Item = Self::Item> + 'static>(self) -> Result<Pin<Box<S>>, Self>;
+}
+
+impl<T: 'static> ScopedBoxStreamDowncast for ScopedBoxStream<'static, 'static, T> {
+    type Item = T;
+
+    fn downcast<S: Stream<Item = T> + 'static>(self) -> Result<Pin<Box<S>>, Self> {
+        if (*self).__type_id() != TypeId::of::<S>() {
+            return Err(self);
+        }
+        // SAFETY: the boxed stream has just been checked to be an `S`, and it is pinned again straight away
+        let stream = unsafe { Box::from_raw(Box::into_raw(Pin::into_inner_unchecked(self)) as *mut S) };
+        Ok(Box::into_pin(stream))
+    }
+}
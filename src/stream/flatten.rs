@@ -0,0 +1,72 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_core::Stream;
+
+pin_project_lite::pin_project! {
+    /// Stream for the [`scope_flatten`](crate::ScopedStreamExt::scope_flatten) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use futures::StreamExt;
+    /// use scoped_futures::{ScopedBoxStreamStream, ScopedStreamExt};
+    ///
+    /// pub struct Db {
+    ///     tables: Vec<Vec<u8>>,
+    /// }
+    ///
+    /// impl Db {
+    ///     async fn scan<'a, F>(&mut self, callback: F) -> Vec<u8>
+    ///     where
+    ///         F: for<'b> FnOnce(&'b mut Self) -> ScopedBoxStreamStream<'a, 'b, u8> + 'a,
+    ///     {
+    ///         callback(self).scope_flatten().collect().await
+    ///     }
+    /// }
+    ///
+    /// futures::executor::block_on(async {
+    ///     let mut db = Db { tables: vec![vec![1, 2], vec![], vec![3]] };
+    ///     let rows = db.scan(|db| {
+    ///         futures::stream::iter(db.tables.iter())
+    ///             .map(|table| futures::stream::iter(table.iter().copied()).scope_boxed())
+    ///             .scope_boxed()
+    ///     }).await;
+    ///     assert_eq!(vec![1, 2, 3], rows);
+    /// });
+    /// # } #[cfg(feature = "alloc")] test();
+    /// ```
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Flatten<S, Inner> {
+        #[pin]
+        stream: S,
+        #[pin]
+        inner: Option<Inner>,
+    }
+}
+
+impl<S, Inner> Flatten<S, Inner> {
+    pub(crate) fn new(stream: S) -> Self {
+        Self { stream, inner: None }
+    }
+}
+
+impl<S: Stream<Item = Inner>, Inner: Stream> Stream for Flatten<S, Inner> {
+    type Item = Inner::Item;
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            if let Some(inner) = this.inner.as_mut().as_pin_mut() {
+                match core::task::ready!(inner.poll_next(cx)) {
+                    Some(item) => return Poll::Ready(Some(item)),
+                    None => this.inner.set(None),
+                }
+            }
+            match core::task::ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(inner) => this.inner.set(Some(inner)),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
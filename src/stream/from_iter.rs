@@ -0,0 +1,41 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_core::Stream;
+
+/// Stream for the [`scoped_stream_from_iter`] function.
+#[must_use = "streams do nothing unless polled"]
+pub struct FromIter<I> {
+    iter: I,
+}
+
+/// Converts an iterator, typically over borrowed data, into a stream which yields each of its items without waiting.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use futures::StreamExt;
+/// use scoped_futures::scoped_stream_from_iter;
+///
+/// let rows = vec![1, 2, 3];
+/// let streamed: Vec<&u8> = futures::executor::block_on(scoped_stream_from_iter(&rows).collect());
+/// assert_eq!(vec![&1, &2, &3], streamed);
+/// # } #[cfg(feature = "alloc")] test();
+/// ```
+pub fn scoped_stream_from_iter<I: IntoIterator>(iter: I) -> FromIter<I::IntoIter> {
+    FromIter { iter: iter.into_iter() }
+}
+
+impl<I> Unpin for FromIter<I> {}
+
+impl<I: Iterator> Stream for FromIter<I> {
+    type Item = I::Item;
+    fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.iter.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -0,0 +1,56 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_core::Stream;
+
+/// Stream for the [`scoped_merge_priority`] function.
+#[must_use = "streams do nothing unless polled"]
+pub struct MergePriority<S> {
+    streams: Vec<Pin<Box<S>>>,
+}
+
+/// Merges `streams` into a single stream which, whenever several of them have an item ready, yields the item of the
+/// stream which was provided first. The merged stream ends once every stream has ended.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use futures::StreamExt;
+/// use scoped_futures::scoped_merge_priority;
+///
+/// let urgent = vec![1, 2];
+/// let routine = vec![10, 20, 30];
+/// let streams = [futures::stream::iter(&urgent), futures::stream::iter(&routine)];
+/// let merged: Vec<&u8> = futures::executor::block_on(scoped_merge_priority(streams).collect());
+/// assert_eq!(vec![&1, &2, &10, &20, &30], merged);
+/// # } #[cfg(feature = "alloc")] test();
+/// ```
+pub fn scoped_merge_priority<I>(streams: I) -> MergePriority<I::Item>
+where
+    I: IntoIterator<Item: Stream>,
+{
+    MergePriority { streams: streams.into_iter().map(Box::pin).collect() }
+}
+
+impl<S> Unpin for MergePriority<S> {}
+
+impl<S: Stream> Stream for MergePriority<S> {
+    type Item = S::Item;
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let streams = &mut self.streams;
+        let mut i = 0;
+        while i < streams.len() {
+            match streams[i].as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => return Poll::Ready(Some(item)),
+                Poll::Ready(None) => drop(streams.remove(i)),
+                Poll::Pending => i += 1,
+            }
+        }
+        match streams.is_empty() {
+            true => Poll::Ready(None),
+            false => Poll::Pending,
+        }
+    }
+}
@@ -0,0 +1,145 @@
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::{any::TypeId, marker::PhantomData, pin::Pin};
+use futures_core::Stream;
+
+use crate::{sealed, ImpliedLifetimeBound};
+
+#[cfg(feature = "alloc")]
+mod bounded_collect;
+#[cfg(feature = "std")]
+mod catch_unwind;
+mod dedup;
+#[cfg(feature = "alloc")]
+mod downcast;
+mod flatten;
+mod from_iter;
+#[cfg(feature = "alloc")]
+mod merge_priority;
+#[cfg(feature = "alloc")]
+mod partition;
+mod throttle;
+mod timeout;
+#[cfg(feature = "alloc")]
+mod window;
+
+#[cfg(feature = "alloc")]
+pub use bounded_collect::{scope_bounded_collect, BoundedCollect, LimitExceeded};
+#[cfg(feature = "std")]
+pub use catch_unwind::{scoped_catch_unwind_stream, CatchUnwindStream, Panic};
+pub use dedup::{scoped_dedup, Dedup};
+#[cfg(feature = "alloc")]
+pub use downcast::ScopedBoxStreamDowncast;
+pub use flatten::Flatten;
+pub use from_iter::{scoped_stream_from_iter, FromIter};
+#[cfg(feature = "alloc")]
+pub use merge_priority::{scoped_merge_priority, MergePriority};
+#[cfg(feature = "alloc")]
+pub use partition::{scoped_partition, PartitionErrs, PartitionOks};
+pub use throttle::{scoped_throttle, Throttle, ThrottleMode};
+pub use timeout::{scoped_stream_timeout, StreamTimeout};
+#[cfg(feature = "alloc")]
+pub use window::{scope_window, Window};
+
+/// A [`Stream`] super-trait with an implied upper bound on the provided lifetime.
+/// This is the [`Stream`] counterpart to [`ScopedFuture`](crate::ScopedFuture).
+pub trait ScopedStream<'upper_bound, 'subject, Bound = ImpliedLifetimeBound<'upper_bound, 'subject>>: Stream
+where
+    Bound: sealed::Sealed,
+{
+    #[doc(hidden)]
+    fn __type_id(&self) -> TypeId
+    where
+        Self: 'static,
+    {
+        TypeId::of::<Self>()
+    }
+}
+
+impl<'upper_bound: 'subject, 'subject, S: Stream + 'subject> ScopedStream<'upper_bound, 'subject> for S {}
+
+/// A boxed stream whose lifetime is upper bounded.
+#[cfg(feature = "alloc")]
+pub type ScopedBoxStream<'upper_bound, 'subject, T> = Pin<Box<dyn ScopedStream<'upper_bound, 'subject, Item = T> + Send + 'subject>>;
+
+/// A boxed stream of boxed streams, all of whose lifetimes are upper bounded by the same scope.
+#[cfg(feature = "alloc")]
+pub type ScopedBoxStreamStream<'upper_bound, 'subject, T> = ScopedBoxStream<'upper_bound, 'subject, ScopedBoxStream<'upper_bound, 'subject, T>>;
+
+/// A non-[`Send`] boxed stream whose lifetime is upper bounded.
+#[cfg(feature = "alloc")]
+pub type ScopedLocalBoxStream<'upper_bound, 'subject, T> = Pin<Box<dyn ScopedStream<'upper_bound, 'subject, Item = T> + 'subject>>;
+
+pin_project_lite::pin_project! {
+    /// A [`Stream`] wrapper type that imposes an upper bound on its lifetime's duration.
+    #[derive(Clone, Debug)]
+    pub struct ScopedStreamWrapper<'upper_bound, 'subject, S> {
+        #[pin]
+        stream: S,
+        scope: ImpliedLifetimeBound<'upper_bound, 'subject>,
+    }
+}
+
+/// An extension trait for [`Stream`] that provides methods for encoding lifetime upper bound information.
+pub trait ScopedStreamExt: Sized {
+    /// Encodes the lifetimes of this [`Stream`]'s captures.
+    fn scoped<'upper_bound, 'subject>(self) -> ScopedStreamWrapper<'upper_bound, 'subject, Self>;
+
+    /// Boxes this [`Stream`] and encodes the lifetimes of its captures.
+    #[cfg(feature = "alloc")]
+    fn scope_boxed<'upper_bound, 'subject>(self) -> ScopedBoxStream<'upper_bound, 'subject, <Self as Stream>::Item>
+    where
+        Self: Send + Stream + 'subject;
+
+    /// Boxes this [`Stream`] and encodes the lifetimes of its captures.
+    #[cfg(feature = "alloc")]
+    fn scope_boxed_local<'upper_bound, 'subject>(self) -> ScopedLocalBoxStream<'upper_bound, 'subject, <Self as Stream>::Item>
+    where
+        Self: Stream + 'subject;
+
+    /// Flattens this stream of streams by concatenating the inner streams in order.
+    fn scope_flatten(self) -> Flatten<Self, <Self as Stream>::Item>
+    where
+        Self: Stream,
+        <Self as Stream>::Item: Stream;
+}
+
+impl<'upper_bound, 'subject, S: Stream> Stream for ScopedStreamWrapper<'upper_bound, 'subject, S> {
+    type Item = S::Item;
+    fn poll_next(self: Pin<&mut Self>, cx: &mut core::task::Context<'_>) -> core::task::Poll<Option<Self::Item>> {
+        self.project().stream.poll_next(cx)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<S: Stream> ScopedStreamExt for S {
+    fn scoped<'upper_bound, 'subject>(self) -> ScopedStreamWrapper<'upper_bound, 'subject, Self> {
+        ScopedStreamWrapper { stream: self, scope: PhantomData }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn scope_boxed<'upper_bound, 'subject>(self) -> ScopedBoxStream<'upper_bound, 'subject, <Self as Stream>::Item>
+    where
+        Self: Send + Stream + 'subject,
+    {
+        Box::pin(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn scope_boxed_local<'upper_bound, 'subject>(self) -> ScopedLocalBoxStream<'upper_bound, 'subject, <Self as Stream>::Item>
+    where
+        Self: Stream + 'subject,
+    {
+        Box::pin(self)
+    }
+
+    fn scope_flatten(self) -> Flatten<Self, <Self as Stream>::Item>
+    where
+        Self: Stream,
+        <Self as Stream>::Item: Stream,
+    {
+        Flatten::new(self)
+    }
+}
@@ -0,0 +1,131 @@
+use crate::fan_out::FanOutWaker;
+use alloc::{boxed::Box, collections::VecDeque, rc::Rc, sync::Arc};
+use core::{
+    cell::RefCell,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use futures_core::{Stream, TryStream};
+
+struct Shared<S: TryStream> {
+    stream: Pin<Box<S>>,
+    oks: VecDeque<S::Ok>,
+    errs: VecDeque<S::Error>,
+    wakers: Arc<FanOutWaker>,
+    waker: Waker,
+    done: bool,
+}
+
+impl<S: TryStream> Shared<S> {
+    fn poll_side(&mut self, cx: &mut Context<'_>, ok_side: bool, other_alive: bool) -> Poll<Option<Result<S::Ok, S::Error>>> {
+        loop {
+            let buffered = match ok_side {
+                true => self.oks.pop_front().map(Ok),
+                false => self.errs.pop_front().map(Err),
+            };
+            if let Some(item) = buffered {
+                return Poll::Ready(Some(item));
+            }
+            if self.done {
+                return Poll::Ready(None);
+            }
+            self.wakers.register(cx.waker());
+            match self.stream.as_mut().try_poll_next(&mut Context::from_waker(&self.waker)) {
+                Poll::Ready(Some(item)) if item.is_ok() == ok_side => return Poll::Ready(Some(item)),
+                Poll::Ready(Some(item)) => {
+                    if other_alive {
+                        match item {
+                            Ok(ok) => self.oks.push_back(ok),
+                            Err(err) => self.errs.push_back(err),
+                        }
+                        self.waker.wake_by_ref();
+                    }
+                }
+                Poll::Ready(None) => {
+                    self.done = true;
+                    self.waker.wake_by_ref();
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Stream of the successful items for the [`scoped_partition`] function.
+#[must_use = "streams do nothing unless polled"]
+pub struct PartitionOks<S: TryStream> {
+    shared: Rc<RefCell<Shared<S>>>,
+}
+
+/// Stream of the failed items for the [`scoped_partition`] function.
+#[must_use = "streams do nothing unless polled"]
+pub struct PartitionErrs<S: TryStream> {
+    shared: Rc<RefCell<Shared<S>>>,
+}
+
+/// Splits `stream` into a stream of its successful items and a stream of its errors.
+///
+/// Both streams are driven by whichever of them is polled, and items meant for the other stream are buffered
+/// until it is polled, unless it has been dropped. Both streams are woken whenever the source stream is,
+/// so dropping one of them while it is pending never stalls the other.
+///
+/// The streams share their state through an [`Rc`], so they are not [`Send`] and are local to the task which polls them.
+/// To box them under the same scope, use [`scope_boxed_local`](crate::ScopedStreamExt::scope_boxed_local).
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use core::task::{Context, Waker};
+/// use futures::StreamExt;
+/// use scoped_futures::{scoped_partition, ScopedLocalBoxStream, ScopedStreamExt};
+///
+/// let rows = vec!["1", "x", "2", "y"];
+/// let parsed = || futures::stream::iter(rows.iter().map(|row| row.parse::<u8>().map_err(|_| *row)));
+/// futures::executor::block_on(async {
+///     let (oks, errs) = scoped_partition(parsed());
+///     let (oks, errs) = futures::future::join(oks.collect::<Vec<_>>(), errs.collect::<Vec<_>>()).await;
+///     assert_eq!(vec![1, 2], oks);
+///     assert_eq!(vec!["x", "y"], errs);
+///
+///     let (oks, errs) = scoped_partition(parsed());
+///     let (oks, errs): (ScopedLocalBoxStream<'_, '_, u8>, ScopedLocalBoxStream<'_, '_, &str>) = (oks.scope_boxed_local(), errs.scope_boxed_local());
+///     assert_eq!(vec![1, 2], oks.collect::<Vec<_>>().await);
+///     assert_eq!(vec!["x", "y"], errs.collect::<Vec<_>>().await);
+///
+///     // the remaining stream is woken even if the stream which last polled the source is dropped
+///     let (tx, rx) = futures::channel::mpsc::unbounded::<Result<u8, &str>>();
+///     let (oks, mut errs) = scoped_partition(rx);
+///     let (oks, ()) = futures::future::join(oks.collect::<Vec<_>>(), async move {
+///         assert!(errs.poll_next_unpin(&mut Context::from_waker(Waker::noop())).is_pending());
+///         drop(errs);
+///         tx.unbounded_send(Ok(1)).unwrap();
+///     }).await;
+///     assert_eq!(vec![1], oks);
+/// });
+/// # } #[cfg(feature = "alloc")] test();
+/// ```
+pub fn scoped_partition<S: TryStream>(stream: S) -> (PartitionOks<S>, PartitionErrs<S>) {
+    let wakers = Arc::new(FanOutWaker::default());
+    let waker = Waker::from(wakers.clone());
+    let shared = Rc::new(RefCell::new(Shared { stream: Box::pin(stream), oks: VecDeque::new(), errs: VecDeque::new(), wakers, waker, done: false }));
+    (PartitionOks { shared: shared.clone() }, PartitionErrs { shared })
+}
+
+impl<S: TryStream> Stream for PartitionOks<S> {
+    type Item = S::Ok;
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let other_alive = Rc::strong_count(&self.shared) > 1;
+        let poll = self.shared.borrow_mut().poll_side(cx, true, other_alive);
+        poll.map(|item| item.and_then(Result::ok))
+    }
+}
+
+impl<S: TryStream> Stream for PartitionErrs<S> {
+    type Item = S::Error;
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let other_alive = Rc::strong_count(&self.shared) > 1;
+        let poll = self.shared.borrow_mut().poll_side(cx, false, other_alive);
+        poll.map(|item| item.and_then(Result::err))
+    }
+}
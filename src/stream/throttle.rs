@@ -0,0 +1,116 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use futures_core::Stream;
+
+use crate::Timer;
+
+const DROP_BUDGET: usize = 32;
+
+/// What [`scoped_throttle`] does with items which arrive before the interval has elapsed.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ThrottleMode {
+    /// Holds back the next item until the interval has elapsed.
+    #[default]
+    Delay,
+    /// Drops items which arrive before the interval has elapsed.
+    Drop,
+}
+
+pin_project_lite::pin_project! {
+    /// Stream for the [`scoped_throttle`] function.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Throttle<S, T: Timer> {
+        #[pin]
+        stream: S,
+        #[pin]
+        sleep: Option<T::Sleep>,
+        timer: T,
+        interval: Duration,
+        mode: ThrottleMode,
+    }
+}
+
+/// Yields at most one item of `stream` per `interval`, delaying or dropping the items in between according to `mode`.
+/// When dropping, a single poll drops a bounded number of items before yielding to the executor, so an always ready
+/// `stream` does not monopolize its task.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use core::{cell::Cell, future::Future, pin::Pin, task::{Context, Poll, Waker}, time::Duration};
+/// use futures::StreamExt;
+/// use scoped_futures::{scoped_throttle, ThrottleMode};
+///
+/// // a sleep on a paused clock, which skips ahead to its deadline when nothing else is ready
+/// pub struct Sleep<'a> {
+///     clock: &'a Cell<u64>,
+///     deadline: u64,
+/// }
+///
+/// impl Future for Sleep<'_> {
+///     type Output = ();
+///     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+///         if self.clock.get() >= self.deadline {
+///             return Poll::Ready(());
+///         }
+///         self.clock.set(self.deadline);
+///         cx.waker().wake_by_ref();
+///         Poll::Pending
+///     }
+/// }
+///
+/// let clock = Cell::new(0);
+/// let timer = |duration: Duration| Sleep { clock: &clock, deadline: clock.get() + duration.as_secs() };
+/// let interval = Duration::from_secs(2);
+/// futures::executor::block_on(async {
+///     let events = futures::stream::iter(["a", "b", "c"]);
+///     let emitted: Vec<_> = scoped_throttle(events, timer, interval, ThrottleMode::Delay).map(|event| (event, clock.get())).collect().await;
+///     assert_eq!(vec![("a", 0), ("b", 2), ("c", 4)], emitted);
+///
+///     clock.set(0);
+///     let events = futures::stream::iter(0..6).inspect(|time| clock.set(*time));
+///     let emitted: Vec<_> = scoped_throttle(events, timer, interval, ThrottleMode::Drop).collect().await;
+///     assert_eq!(vec![0, 2, 4], emitted);
+/// });
+///
+/// let pulled = Cell::new(0);
+/// let never = |_: Duration| futures::future::pending::<()>();
+/// let mut throttled = scoped_throttle(futures::stream::repeat(()).inspect(|_| pulled.set(pulled.get() + 1)), never, interval, ThrottleMode::Drop);
+/// let mut cx = Context::from_waker(Waker::noop());
+/// assert_eq!(Poll::Ready(Some(())), throttled.poll_next_unpin(&mut cx));
+/// assert_eq!(Poll::Pending, throttled.poll_next_unpin(&mut cx));
+/// assert!(pulled.get() < 100);
+/// # } #[cfg(feature = "alloc")] test();
+/// ```
+pub fn scoped_throttle<S: Stream, T: Timer>(stream: S, timer: T, interval: Duration, mode: ThrottleMode) -> Throttle<S, T> {
+    Throttle { stream, sleep: None, timer, interval, mode }
+}
+
+impl<S: Stream, T: Timer> Stream for Throttle<S, T> {
+    type Item = S::Item;
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if *this.mode == ThrottleMode::Delay {
+            if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
+                core::task::ready!(sleep.poll(cx));
+                this.sleep.set(None);
+            }
+        }
+        for _ in 0..=DROP_BUDGET {
+            let Some(item) = core::task::ready!(this.stream.as_mut().poll_next(cx)) else { return Poll::Ready(None) };
+            if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
+                if sleep.poll(cx).is_pending() {
+                    continue;
+                }
+            }
+            this.sleep.set(Some(this.timer.sleep(*this.interval)));
+            return Poll::Ready(Some(item));
+        }
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
@@ -0,0 +1,90 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use futures_core::Stream;
+
+use crate::Timer;
+
+pin_project_lite::pin_project! {
+    /// Stream for the [`scoped_stream_timeout`] function.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct StreamTimeout<S, Sleep> {
+        #[pin]
+        stream: S,
+        #[pin]
+        sleep: Sleep,
+        done: bool,
+    }
+}
+
+/// Yields the items of `stream` until `total` has elapsed, then ends the stream. The deadline is checked before
+/// `stream` is polled, so an item pulled from `stream` is always yielded, even if `total` elapsed while producing it.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use core::{cell::Cell, future::Future, pin::{pin, Pin}, task::{Context, Poll}, time::Duration};
+/// use futures::StreamExt;
+/// use scoped_futures::scoped_stream_timeout;
+///
+/// // a sleep on a paused clock which only moves forward as rows are read
+/// pub struct Sleep<'a> {
+///     clock: &'a Cell<u64>,
+///     deadline: u64,
+/// }
+///
+/// impl Future for Sleep<'_> {
+///     type Output = ();
+///     fn poll(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<()> {
+///         match self.clock.get() >= self.deadline {
+///             true => Poll::Ready(()),
+///             false => Poll::Pending,
+///         }
+///     }
+/// }
+///
+/// let read_at = vec![0, 1, 2, 4, 5];
+/// let clock = Cell::new(0);
+/// let timer = |duration: Duration| Sleep { clock: &clock, deadline: clock.get() + duration.as_secs() };
+/// let rows = || futures::stream::iter(&read_at).inspect(|time| clock.set(**time));
+/// futures::executor::block_on(async {
+///     // the row read at 4 is still yielded, even though the deadline passed while it was read
+///     let read: Vec<&u64> = scoped_stream_timeout(rows(), timer, Duration::from_secs(3)).collect().await;
+///     assert_eq!(vec![&0, &1, &2, &4], read);
+///
+///     clock.set(0);
+///     let read: Vec<&u64> = scoped_stream_timeout(rows(), timer, Duration::from_secs(10)).collect().await;
+///     assert_eq!(vec![&0, &1, &2, &4, &5], read);
+///
+///     // an exhausted stream is not polled again
+///     let never = |_: Duration| futures::future::pending::<()>();
+///     let rows = futures::stream::unfold(0, |row| async move { (row < 2).then_some((row, row + 1)) });
+///     let mut rows = pin!(scoped_stream_timeout(rows, never, Duration::from_secs(3)));
+///     assert_eq!(vec![0, 1], rows.by_ref().collect::<Vec<_>>().await);
+///     assert_eq!(None, rows.next().await);
+/// });
+/// # } #[cfg(feature = "alloc")] test();
+/// ```
+pub fn scoped_stream_timeout<S: Stream, T: Timer>(stream: S, timer: T, total: Duration) -> StreamTimeout<S, T::Sleep> {
+    StreamTimeout { stream, sleep: timer.sleep(total), done: false }
+}
+
+impl<S: Stream, Sleep: Future<Output = ()>> Stream for StreamTimeout<S, Sleep> {
+    type Item = S::Item;
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+        if this.sleep.poll(cx).is_ready() {
+            *this.done = true;
+            return Poll::Ready(None);
+        }
+        let next = core::task::ready!(this.stream.poll_next(cx));
+        *this.done = next.is_none();
+        Poll::Ready(next)
+    }
+}
@@ -0,0 +1,117 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_core::Stream;
+
+pin_project_lite::pin_project! {
+    /// Stream for the [`scope_window`] function.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Window<S: Stream> {
+        #[pin]
+        futures: S,
+        done: bool,
+        refilling: bool,
+        in_flight: Vec<Pin<Box<S::Item>>>,
+        min: usize,
+        max: usize,
+    }
+}
+
+/// Runs the futures yielded by `futures` with at most `max` of them in flight at once, yielding their outputs in
+/// completion order. Once fewer than `min` futures are in flight, futures are taken from `futures` until `max` of
+/// them are in flight again, so the window is refilled in batches. Futures already in flight are polled on every
+/// poll of the window, so `futures` may itself wait on their outputs.
+///
+/// # Panics
+/// Panics if `min` is zero or `max` is less than `min`.
+///
+/// # Example
+/// ```
+/// # fn test() {
+/// use core::cell::{Cell, RefCell};
+/// use futures::StreamExt;
+/// use scoped_futures::scope_window;
+/// # use scoped_futures::yield_now;
+///
+/// let rows = vec![1, 2, 3, 4, 5, 6];
+/// let in_flight = Cell::new(0);
+/// let observed = RefCell::new(vec![]);
+/// let pulled_at = RefCell::new(vec![]);
+/// let job = |row: &u8| {
+///     pulled_at.borrow_mut().push(in_flight.get());
+///     in_flight.set(in_flight.get() + 1);
+///     let (in_flight, observed, row) = (&in_flight, &observed, *row);
+///     async move {
+///         observed.borrow_mut().push(in_flight.get());
+///         yield_now().await;
+///         yield_now().await;
+///         in_flight.set(in_flight.get() - 1);
+///         row * 10
+///     }
+/// };
+/// let jobs = futures::stream::iter(&rows).map(job);
+/// let mut outputs: Vec<u8> = futures::executor::block_on(scope_window(jobs, 2, 3).collect());
+/// outputs.sort();
+/// assert_eq!(vec![10, 20, 30, 40, 50, 60], outputs);
+/// assert!(observed.into_inner().iter().all(|in_flight| *in_flight <= 3));
+/// // a refill only starts once fewer than 2 jobs are in flight, and then continues until 3 are
+/// let pulled_at = pulled_at.into_inner();
+/// assert_eq!(0, pulled_at[0]);
+/// assert!(pulled_at.windows(2).all(|pulled| pulled[1] < 2 || pulled[1] == pulled[0] + 1));
+///
+/// // the source only produces the next job once the previous one has completed
+/// let (tx, rx) = futures::channel::mpsc::unbounded();
+/// tx.unbounded_send(1).unwrap();
+/// let chained = rx.take(3).map(|row: u8| {
+///     let tx = tx.clone();
+///     async move {
+///         yield_now().await;
+///         let _ = tx.unbounded_send(row + 1);
+///         row * 10
+///     }
+/// });
+/// let outputs: Vec<u8> = futures::executor::block_on(scope_window(chained, 2, 3).collect());
+/// assert_eq!(vec![10, 20, 30], outputs);
+/// # } #[cfg(feature = "alloc")] test();
+/// ```
+pub fn scope_window<S>(futures: S, min: usize, max: usize) -> Window<S>
+where
+    S: Stream<Item: Future>,
+{
+    assert!(min > 0 && min <= max, "scope_window called with a window of {min}..={max}");
+    Window { futures, done: false, refilling: true, in_flight: Vec::with_capacity(max), min, max }
+}
+
+impl<S: Stream<Item: Future>> Stream for Window<S> {
+    type Item = <S::Item as Future>::Output;
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if this.in_flight.len() < *this.min {
+            *this.refilling = true;
+        }
+        while *this.refilling && !*this.done {
+            if this.in_flight.len() == *this.max {
+                *this.refilling = false;
+                break;
+            }
+            match this.futures.as_mut().poll_next(cx) {
+                Poll::Ready(Some(future)) => this.in_flight.push(Box::pin(future)),
+                Poll::Ready(None) => *this.done = true,
+                Poll::Pending => break,
+            }
+        }
+        if this.in_flight.is_empty() && *this.done {
+            return Poll::Ready(None);
+        }
+        for i in 0..this.in_flight.len() {
+            if let Poll::Ready(output) = this.in_flight[i].as_mut().poll(cx) {
+                drop(this.in_flight.remove(i));
+                return Poll::Ready(Some(output));
+            }
+        }
+        Poll::Pending
+    }
+}
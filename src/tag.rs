@@ -0,0 +1,58 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_tag`](crate::ScopedFutureExt::scope_tag) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use futures::{stream::FuturesUnordered, StreamExt};
+    /// use scoped_futures::ScopedFutureExt;
+    ///
+    /// async fn len(row: &str) -> usize {
+    ///     row.len()
+    /// }
+    ///
+    /// let rows = vec![String::from("a"), String::from("bb"), String::from("ccc")];
+    /// let mut outputs: Vec<(usize, usize)> = futures::executor::block_on(
+    ///     rows.iter().enumerate().map(|(id, row)| len(row).scope_tag(id)).collect::<FuturesUnordered<_>>().collect(),
+    /// );
+    /// outputs.sort();
+    /// assert_eq!(vec![(0, 1), (1, 2), (2, 3)], outputs);
+    /// # } test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Tagged<Fut, Id> {
+        #[pin]
+        future: Fut,
+        id: Option<Id>,
+    }
+}
+
+impl<Fut, Id> Tagged<Fut, Id> {
+    pub(crate) fn new(future: Fut, id: Id) -> Self {
+        Self { future, id: Some(id) }
+    }
+
+    /// Returns the id this future was tagged with.
+    ///
+    /// # Panics
+    /// Panics if this future has already completed.
+    pub fn id(&self) -> &Id {
+        self.id.as_ref().expect("Tagged future has already completed")
+    }
+}
+
+impl<Fut: Future, Id> Future for Tagged<Fut, Id> {
+    type Output = (Id, Fut::Output);
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let output = core::task::ready!(this.future.poll(cx));
+        let id = this.id.take().expect("Tagged polled after completion");
+        Poll::Ready((id, output))
+    }
+}
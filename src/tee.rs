@@ -0,0 +1,120 @@
+use alloc::rc::Rc;
+use core::{
+    cell::{Cell, RefCell},
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use futures_core::Stream;
+
+#[derive(Default)]
+struct State {
+    count: Cell<usize>,
+    finished: Cell<bool>,
+    waker: RefCell<Option<Waker>>,
+}
+
+impl State {
+    fn wake(&self) {
+        if let Some(waker) = self.waker.borrow_mut().take() {
+            waker.wake();
+        }
+    }
+}
+
+struct Handle(Rc<State>);
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        self.0.wake();
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Stream for the [`scope_tee_stream`](crate::ScopedFutureExt::scope_tee_stream) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use futures::StreamExt;
+    /// use scoped_futures::ScopedFutureExt;
+    ///
+    /// async fn select(rows: &[u8]) -> Vec<&u8> {
+    ///     rows.iter().filter(|row| **row % 2 == 0).collect()
+    /// }
+    ///
+    /// futures::executor::block_on(async {
+    ///     let rows = vec![1, 2, 3, 4];
+    ///     let (stream, summary) = select(&rows).scope_tee_stream();
+    ///     let (items, count) = futures::future::join(stream.collect::<Vec<&u8>>(), summary).await;
+    ///     assert_eq!(vec![&2, &4], items);
+    ///     assert_eq!(Some(items.len()), count);
+    /// });
+    /// # } #[cfg(feature = "alloc")] test();
+    /// ```
+    #[must_use = "streams do nothing unless polled"]
+    pub struct TeeStream<Fut, I> {
+        #[pin]
+        future: Option<Fut>,
+        items: Option<I>,
+        handle: Handle,
+    }
+}
+
+/// Future for the [`scope_tee_stream`](crate::ScopedFutureExt::scope_tee_stream) method, resolving to the
+/// number of items yielded by its [`TeeStream`] once that stream is exhausted, or to [`None`] if the stream
+/// is dropped beforehand.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TeeSummary {
+    state: Rc<State>,
+}
+
+impl<Fut, I> TeeStream<Fut, I> {
+    pub(crate) fn new(future: Fut) -> (Self, TeeSummary) {
+        let state = Rc::new(State::default());
+        (Self { future: Some(future), items: None, handle: Handle(state.clone()) }, TeeSummary { state })
+    }
+}
+
+impl<Fut, I> Stream for TeeStream<Fut, I>
+where
+    Fut: Future<Output: IntoIterator<IntoIter = I>>,
+    I: Iterator<Item = <Fut::Output as IntoIterator>::Item>,
+{
+    type Item = I::Item;
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if let Some(future) = this.future.as_mut().as_pin_mut() {
+            let items = core::task::ready!(future.poll(cx));
+            this.future.set(None);
+            *this.items = Some(items.into_iter());
+        }
+        let state = &this.handle.0;
+        match this.items.as_mut().and_then(Iterator::next) {
+            Some(item) => {
+                state.count.set(state.count.get() + 1);
+                Poll::Ready(Some(item))
+            }
+            None => {
+                if !state.finished.replace(true) {
+                    state.wake();
+                }
+                Poll::Ready(None)
+            }
+        }
+    }
+}
+
+impl Future for TeeSummary {
+    type Output = Option<usize>;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.state.finished.get() {
+            return Poll::Ready(Some(self.state.count.get()));
+        }
+        if Rc::strong_count(&self.state) == 1 {
+            return Poll::Ready(None);
+        }
+        *self.state.waker.borrow_mut() = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
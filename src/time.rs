@@ -0,0 +1,19 @@
+use core::{future::Future, time::Duration};
+
+/// A source of delays for time based combinators, which keeps this crate independent of any particular async runtime.
+///
+/// Functions returning a sleep future, such as `tokio::time::sleep`, implement this trait.
+pub trait Timer {
+    /// The future returned by [`sleep`](Timer::sleep).
+    type Sleep: Future<Output = ()>;
+
+    /// Returns a future which completes once `duration` has elapsed.
+    fn sleep(&self, duration: Duration) -> Self::Sleep;
+}
+
+impl<F: Fn(Duration) -> Sleep, Sleep: Future<Output = ()>> Timer for F {
+    type Sleep = Sleep;
+    fn sleep(&self, duration: Duration) -> Self::Sleep {
+        self(duration)
+    }
+}
@@ -0,0 +1,55 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_timeout_or`](crate::ScopedFutureExt::scope_timeout_or) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use core::time::Duration;
+    /// use scoped_futures::ScopedFutureExt;
+    ///
+    /// let rows = vec![1, 2, 3];
+    /// let expired = |_: Duration| futures::future::ready(());
+    /// let never = |_: Duration| futures::future::pending();
+    /// futures::executor::block_on(async {
+    ///     let count = async { rows.len() }.scope_timeout_or(never, Duration::from_secs(1), 0).await;
+    ///     assert_eq!(3, count);
+    ///     let count = futures::future::pending::<usize>().scope_timeout_or(expired, Duration::from_secs(1), 0).await;
+    ///     assert_eq!(0, count);
+    /// });
+    /// # } test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct TimeoutOr<Fut: Future, Sleep> {
+        #[pin]
+        future: Fut,
+        #[pin]
+        sleep: Sleep,
+        default: Option<Fut::Output>,
+    }
+}
+
+impl<Fut: Future, Sleep> TimeoutOr<Fut, Sleep> {
+    pub(crate) fn new(future: Fut, sleep: Sleep, default: Fut::Output) -> Self {
+        Self { future, sleep, default: Some(default) }
+    }
+}
+
+impl<Fut: Future, Sleep: Future<Output = ()>> Future for TimeoutOr<Fut, Sleep> {
+    type Output = Fut::Output;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        assert!(this.default.is_some(), "TimeoutOr polled after completion");
+        if let Poll::Ready(output) = this.future.poll(cx) {
+            *this.default = None;
+            return Poll::Ready(output);
+        }
+        core::task::ready!(this.sleep.poll(cx));
+        Poll::Ready(this.default.take().expect("TimeoutOr polled after completion"))
+    }
+}
@@ -0,0 +1,85 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A [`Future`] which can report how far it has gotten before completing.
+pub trait Progress: Future {
+    /// The partial result of an incomplete future.
+    type Partial;
+
+    /// Returns the partial result accumulated so far.
+    fn progress(self: Pin<&mut Self>) -> Self::Partial;
+}
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_timeout_partial`](crate::ScopedFutureExt::scope_timeout_partial) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use core::{future::Future, pin::Pin, task::{Context, Poll}, time::Duration};
+    /// use scoped_futures::{Progress, ScopedFutureExt};
+    ///
+    /// // sums one row per poll
+    /// pub struct Sum<'a> {
+    ///     rows: &'a [u8],
+    ///     sum: u8,
+    /// }
+    ///
+    /// impl Future for Sum<'_> {
+    ///     type Output = u8;
+    ///     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u8> {
+    ///         let Some((row, rows)) = self.rows.split_first() else { return Poll::Ready(self.sum) };
+    ///         self.sum += row;
+    ///         self.rows = rows;
+    ///         cx.waker().wake_by_ref();
+    ///         Poll::Pending
+    ///     }
+    /// }
+    ///
+    /// impl Progress for Sum<'_> {
+    ///     type Partial = u8;
+    ///     fn progress(self: Pin<&mut Self>) -> u8 {
+    ///         self.sum
+    ///     }
+    /// }
+    ///
+    /// let rows = vec![1, 2, 3];
+    /// let expired = |_: Duration| futures::future::ready(());
+    /// let never = |_: Duration| futures::future::pending();
+    /// futures::executor::block_on(async {
+    ///     let sum = Sum { rows: &rows, sum: 0 }.scope_timeout_partial(never, Duration::from_secs(1)).await;
+    ///     assert_eq!(Ok(6), sum);
+    ///     let sum = Sum { rows: &rows, sum: 0 }.scope_timeout_partial(expired, Duration::from_secs(1)).await;
+    ///     assert_eq!(Err(1), sum);
+    /// });
+    /// # } test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct TimeoutPartial<Fut, Sleep> {
+        #[pin]
+        future: Fut,
+        #[pin]
+        sleep: Sleep,
+    }
+}
+
+impl<Fut, Sleep> TimeoutPartial<Fut, Sleep> {
+    pub(crate) fn new(future: Fut, sleep: Sleep) -> Self {
+        Self { future, sleep }
+    }
+}
+
+impl<Fut: Progress, Sleep: Future<Output = ()>> Future for TimeoutPartial<Fut, Sleep> {
+    type Output = Result<Fut::Output, Fut::Partial>;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        if let Poll::Ready(output) = this.future.as_mut().poll(cx) {
+            return Poll::Ready(Ok(output));
+        }
+        core::task::ready!(this.sleep.poll(cx));
+        Poll::Ready(Err(this.future.progress()))
+    }
+}
@@ -0,0 +1,134 @@
+use core::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_core::TryFuture;
+
+#[cfg(feature = "alloc")]
+use crate::BoxErr;
+use crate::{sealed, ImpliedLifetimeBound, IntoTryStream, LogErrAndDefault, ScopedFuture};
+
+/// A [`TryFuture`] super-trait with an implied upper bound on the provided lifetime.
+/// This is the fallible counterpart to [`ScopedFuture`].
+pub trait TryScopedFuture<'upper_bound, 'subject, Bound = ImpliedLifetimeBound<'upper_bound, 'subject>>:
+    TryFuture + ScopedFuture<'upper_bound, 'subject, Bound>
+where
+    Bound: sealed::Sealed,
+{
+}
+
+impl<'upper_bound: 'subject, 'subject, Fut: TryFuture + 'subject> TryScopedFuture<'upper_bound, 'subject> for Fut {}
+
+/// An extension trait for [`TryFuture`] that provides combinators for fallible scoped futures.
+pub trait TryScopedFutureExt: TryFuture + Sized {
+    /// Attaches `context` to the error of this future if it fails.
+    fn scope_context<C>(self, context: C) -> ErrorContext<Self, C>;
+
+    /// Converts this future of a collection into a stream which yields each item of the collection,
+    /// or the error if this future fails.
+    fn scope_into_try_stream(self) -> IntoTryStream<Self, <Self::Ok as IntoIterator>::IntoIter>
+    where
+        Self::Ok: IntoIterator;
+
+    /// Boxes the error of this future into a [`ScopedBoxError`](crate::ScopedBoxError), which may keep borrowing for `'subject`.
+    #[cfg(feature = "alloc")]
+    fn scope_box_err<'subject>(self) -> BoxErr<'subject, Self>
+    where
+        Self::Error: core::error::Error + Send + 'subject;
+
+    /// Resolves to the success value of this future, or passes its error to `log` and resolves to `default` if it fails.
+    fn scope_log_err_and_default<F: FnOnce(Self::Error)>(self, default: Self::Ok, log: F) -> LogErrAndDefault<Self, F>;
+}
+
+impl<Fut: TryFuture> TryScopedFutureExt for Fut {
+    fn scope_context<C>(self, context: C) -> ErrorContext<Self, C> {
+        ErrorContext { future: self, context: Some(context) }
+    }
+
+    fn scope_into_try_stream(self) -> IntoTryStream<Self, <Self::Ok as IntoIterator>::IntoIter>
+    where
+        Self::Ok: IntoIterator,
+    {
+        IntoTryStream::new(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn scope_box_err<'subject>(self) -> BoxErr<'subject, Self>
+    where
+        Self::Error: core::error::Error + Send + 'subject,
+    {
+        BoxErr::new(self)
+    }
+
+    fn scope_log_err_and_default<F: FnOnce(Self::Error)>(self, default: Self::Ok, log: F) -> LogErrAndDefault<Self, F> {
+        LogErrAndDefault::new(self, default, log)
+    }
+}
+
+/// An error annotated with the context in which it occurred.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ContextError<C, E> {
+    /// The context attached to the error.
+    pub context: C,
+    /// The original error.
+    pub error: E,
+}
+
+impl<C: fmt::Display, E: fmt::Display> fmt::Display for ContextError<C, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.context, self.error)
+    }
+}
+
+impl<C: fmt::Debug + fmt::Display, E: core::error::Error + 'static> core::error::Error for ContextError<C, E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_context`](TryScopedFutureExt::scope_context) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use scoped_futures::{ContextError, TryScopedFutureExt};
+    ///
+    /// pub struct Db {
+    ///     count: u8,
+    /// }
+    ///
+    /// async fn increment(db: &mut Db, is_ok: bool) -> Result<u8, &'static str> {
+    ///     db.count += 1;
+    ///     if is_ok { Ok(db.count) } else { Err("constraint violated") }
+    /// }
+    ///
+    /// futures::executor::block_on(async {
+    ///     let mut db = Db { count: 0 };
+    ///     assert_eq!(Ok(1), increment(&mut db, true).scope_context("increment").await);
+    ///     assert_eq!(
+    ///         Err(ContextError { context: "increment", error: "constraint violated" }),
+    ///         increment(&mut db, false).scope_context("increment").await,
+    ///     );
+    /// });
+    /// # } test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct ErrorContext<Fut, C> {
+        #[pin]
+        future: Fut,
+        context: Option<C>,
+    }
+}
+
+impl<Fut: TryFuture, C> Future for ErrorContext<Fut, C> {
+    type Output = Result<Fut::Ok, ContextError<C, Fut::Error>>;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let output = core::task::ready!(this.future.try_poll(cx));
+        let context = this.context.take().expect("ErrorContext polled after completion");
+        Poll::Ready(output.map_err(|error| ContextError { context, error }))
+    }
+}
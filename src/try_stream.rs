@@ -0,0 +1,61 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_core::{Stream, TryFuture};
+
+pin_project_lite::pin_project! {
+    /// Stream for the [`scope_into_try_stream`](crate::TryScopedFutureExt::scope_into_try_stream) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use futures::StreamExt;
+    /// use scoped_futures::TryScopedFutureExt;
+    ///
+    /// async fn select<'a>(rows: &'a [u8], is_ok: bool) -> Result<Vec<&'a u8>, &'static str> {
+    ///     if is_ok { Ok(rows.iter().collect()) } else { Err("connection reset") }
+    /// }
+    ///
+    /// futures::executor::block_on(async {
+    ///     let rows = vec![1, 2];
+    ///     let items: Vec<_> = select(&rows, true).scope_into_try_stream().collect().await;
+    ///     assert_eq!(vec![Ok(&1), Ok(&2)], items);
+    ///     let items: Vec<_> = select(&rows, false).scope_into_try_stream().collect().await;
+    ///     assert_eq!(vec![Err("connection reset")], items);
+    /// });
+    /// # } test();
+    /// ```
+    #[must_use = "streams do nothing unless polled"]
+    pub struct IntoTryStream<Fut, I> {
+        #[pin]
+        future: Option<Fut>,
+        items: Option<I>,
+    }
+}
+
+impl<Fut, I> IntoTryStream<Fut, I> {
+    pub(crate) fn new(future: Fut) -> Self {
+        Self { future: Some(future), items: None }
+    }
+}
+
+impl<Fut, I> Stream for IntoTryStream<Fut, I>
+where
+    Fut: TryFuture<Ok: IntoIterator<IntoIter = I>>,
+    I: Iterator<Item = <Fut::Ok as IntoIterator>::Item>,
+{
+    type Item = Result<I::Item, Fut::Error>;
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if let Some(future) = this.future.as_mut().as_pin_mut() {
+            let output = core::task::ready!(future.try_poll(cx));
+            this.future.set(None);
+            match output {
+                Ok(items) => *this.items = Some(items.into_iter()),
+                Err(error) => return Poll::Ready(Some(Err(error))),
+            }
+        }
+        Poll::Ready(this.items.as_mut().and_then(Iterator::next).map(Ok))
+    }
+}
@@ -0,0 +1,59 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use std::time::Instant;
+
+pin_project_lite::pin_project! {
+    /// Future for the [`scope_warn_slow`](crate::ScopedFutureExt::scope_warn_slow) method.
+    ///
+    /// # Example
+    /// ```
+    /// # fn test() {
+    /// use core::time::Duration;
+    /// use scoped_futures::ScopedFutureExt;
+    ///
+    /// let rows = vec![1, 2, 3];
+    /// let mut warnings = vec![];
+    /// futures::executor::block_on(async {
+    ///     let blocking = async {
+    ///         std::thread::sleep(Duration::from_millis(20));
+    ///         rows.len()
+    ///     };
+    ///     assert_eq!(3, blocking.scope_warn_slow(Duration::from_millis(10), |elapsed| warnings.push(elapsed)).await);
+    ///     assert_eq!(3, async { rows.len() }.scope_warn_slow(Duration::from_secs(10), |elapsed| warnings.push(elapsed)).await);
+    /// });
+    /// assert_eq!(1, warnings.len());
+    /// assert!(warnings[0] >= Duration::from_millis(20));
+    /// # } #[cfg(feature = "std")] test();
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct WarnSlow<Fut, F> {
+        #[pin]
+        future: Fut,
+        threshold: Duration,
+        warn: F,
+    }
+}
+
+impl<Fut, F> WarnSlow<Fut, F> {
+    pub(crate) fn new(future: Fut, threshold: Duration, warn: F) -> Self {
+        Self { future, threshold, warn }
+    }
+}
+
+impl<Fut: Future, F: FnMut(Duration)> Future for WarnSlow<Fut, F> {
+    type Output = Fut::Output;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let start = Instant::now();
+        let poll = this.future.poll(cx);
+        let elapsed = start.elapsed();
+        if elapsed > *this.threshold {
+            (this.warn)(elapsed);
+        }
+        poll
+    }
+}
@@ -0,0 +1,17 @@
+use core::task::Poll;
+
+/// Yields to the executor once, waking the task so that it is polled again straight away.
+///
+/// This backs the examples of this crate and is not part of its public API.
+#[doc(hidden)]
+pub async fn yield_now() {
+    let mut yielded = false;
+    core::future::poll_fn(|cx| {
+        if core::mem::replace(&mut yielded, true) {
+            return Poll::Ready(());
+        }
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    })
+    .await
+}